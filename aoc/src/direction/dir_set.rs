@@ -0,0 +1,231 @@
+use std::ops::{BitAnd, BitOr, Index, IndexMut, Not, Sub};
+
+use super::Dir;
+
+/// A set of directions, stored as four bits
+///
+/// # Examples
+/// ```
+/// use aoc::direction::{Dir, DirSet};
+/// let mut seen = DirSet::EMPTY;
+/// assert!(seen.insert(Dir::East));
+/// assert!(!seen.insert(Dir::East));
+/// seen.insert(Dir::North);
+/// assert_eq!(seen.iter().collect::<Vec<_>>(), vec![Dir::North, Dir::East]);
+/// assert_eq!(!seen, DirSet::from_iter([Dir::South, Dir::West]));
+/// ```
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct DirSet(u8);
+
+impl DirSet {
+    pub const EMPTY: DirSet = DirSet(0);
+    pub const ALL: DirSet = DirSet(0b1111);
+
+    fn bit(dir: Dir) -> u8 {
+        1 << dir.index()
+    }
+
+    /// Add a direction, returning whether it was newly added
+    pub fn insert(&mut self, dir: Dir) -> bool {
+        let added = !self.contains(dir);
+        self.0 |= Self::bit(dir);
+        added
+    }
+
+    /// Remove a direction, returning whether it was present
+    pub fn remove(&mut self, dir: Dir) -> bool {
+        let present = self.contains(dir);
+        self.0 &= !Self::bit(dir);
+        present
+    }
+
+    pub fn contains(&self, dir: Dir) -> bool {
+        self.0 & Self::bit(dir) != 0
+    }
+
+    pub fn len(&self) -> usize {
+        self.0.count_ones() as usize
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.0 == 0
+    }
+
+    pub fn union(&self, other: Self) -> Self {
+        Self(self.0 | other.0)
+    }
+
+    pub fn intersection(&self, other: Self) -> Self {
+        Self(self.0 & other.0)
+    }
+
+    pub fn difference(&self, other: Self) -> Self {
+        Self(self.0 & !other.0)
+    }
+
+    /// Iterate over the directions in the set, clockwise from north
+    pub fn iter(&self) -> impl Iterator<Item = Dir> {
+        let set = *self;
+        Dir::ALL.into_iter().filter(move |&dir| set.contains(dir))
+    }
+}
+
+impl std::fmt::Debug for DirSet {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_set().entries(self.iter()).finish()
+    }
+}
+
+impl From<Dir> for DirSet {
+    fn from(dir: Dir) -> Self {
+        Self(Self::bit(dir))
+    }
+}
+
+impl FromIterator<Dir> for DirSet {
+    fn from_iter<I: IntoIterator<Item = Dir>>(iter: I) -> Self {
+        let mut set = Self::EMPTY;
+        for dir in iter {
+            set.insert(dir);
+        }
+        set
+    }
+}
+
+impl Extend<Dir> for DirSet {
+    fn extend<I: IntoIterator<Item = Dir>>(&mut self, iter: I) {
+        for dir in iter {
+            self.insert(dir);
+        }
+    }
+}
+
+impl BitOr for DirSet {
+    type Output = Self;
+
+    fn bitor(self, rhs: Self) -> Self {
+        self.union(rhs)
+    }
+}
+
+impl BitAnd for DirSet {
+    type Output = Self;
+
+    fn bitand(self, rhs: Self) -> Self {
+        self.intersection(rhs)
+    }
+}
+
+impl Sub for DirSet {
+    type Output = Self;
+
+    fn sub(self, rhs: Self) -> Self {
+        self.difference(rhs)
+    }
+}
+
+impl Not for DirSet {
+    type Output = Self;
+
+    fn not(self) -> Self {
+        Self::ALL.difference(self)
+    }
+}
+
+/// A value for each direction
+///
+/// # Examples
+/// ```
+/// use aoc::direction::{Dir, DirMap};
+/// let mut costs = DirMap::from_fn(|dir| if dir == Dir::North { 10 } else { 1 });
+/// costs[Dir::East] += 5;
+/// assert_eq!(costs[Dir::North], 10);
+/// assert_eq!(costs.values().sum::<i32>(), 18);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct DirMap<T>([T; 4]);
+
+impl<T> DirMap<T> {
+    /// Create a map by calling a function for each direction
+    pub fn from_fn<F: FnMut(Dir) -> T>(mut f: F) -> Self {
+        Self(Dir::ALL.map(&mut f))
+    }
+
+    /// Iterate over each direction and its value, clockwise from north
+    pub fn iter(&self) -> impl Iterator<Item = (Dir, &T)> {
+        Dir::ALL.into_iter().zip(self.0.iter())
+    }
+
+    /// Iterate over each direction and a mutable reference to its value
+    pub fn iter_mut(&mut self) -> impl Iterator<Item = (Dir, &mut T)> {
+        Dir::ALL.into_iter().zip(self.0.iter_mut())
+    }
+
+    pub fn values(&self) -> impl Iterator<Item = &T> {
+        self.0.iter()
+    }
+}
+
+impl<T> Index<Dir> for DirMap<T> {
+    type Output = T;
+
+    fn index(&self, dir: Dir) -> &T {
+        &self.0[dir.index()]
+    }
+}
+
+impl<T> IndexMut<Dir> for DirMap<T> {
+    fn index_mut(&mut self, dir: Dir) -> &mut T {
+        &mut self.0[dir.index()]
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use proptest::prelude::*;
+    use std::collections::HashSet;
+
+    fn any_dirs() -> impl Strategy<Value = Vec<Dir>> {
+        prop::collection::vec(prop::sample::select(Dir::ALL.to_vec()), 0..6)
+    }
+
+    proptest! {
+        #[test]
+        fn matches_hash_set(a in any_dirs(), b in any_dirs()) {
+            let (set_a, set_b) = (DirSet::from_iter(a.clone()), DirSet::from_iter(b.clone()));
+            let (hash_a, hash_b): (HashSet<_>, HashSet<_>) =
+                (a.into_iter().collect(), b.into_iter().collect());
+
+            prop_assert_eq!(set_a.len(), hash_a.len());
+            prop_assert_eq!(set_a.iter().collect::<HashSet<_>>(), hash_a.clone());
+            prop_assert_eq!((set_a | set_b).iter().collect::<HashSet<_>>(), &hash_a | &hash_b);
+            prop_assert_eq!((set_a & set_b).iter().collect::<HashSet<_>>(), &hash_a & &hash_b);
+            prop_assert_eq!((set_a - set_b).iter().collect::<HashSet<_>>(), &hash_a - &hash_b);
+            prop_assert_eq!((!set_a).len(), 4 - hash_a.len());
+        }
+    }
+
+    #[test]
+    fn insert_and_remove() {
+        let mut set = DirSet::from(Dir::West);
+        assert!(set.remove(Dir::West));
+        assert!(!set.remove(Dir::West));
+        assert!(set.is_empty());
+        set.extend(Dir::ALL);
+        assert_eq!(set, DirSet::ALL);
+        assert_eq!(format!("{set:?}"), "{North, East, South, West}");
+    }
+
+    #[test]
+    fn map_by_direction() {
+        let mut map = DirMap::from_fn(|dir| dir.opposite());
+        assert!(map
+            .iter()
+            .all(|(dir, &opposite)| dir.opposite() == opposite));
+        for (dir, value) in map.iter_mut() {
+            *value = dir;
+        }
+        assert_eq!(map[Dir::South], Dir::South);
+    }
+}
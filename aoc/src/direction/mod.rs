@@ -0,0 +1,480 @@
+mod dir_set;
+mod pose;
+
+use smallvec::{smallvec, SmallVec};
+
+pub use self::dir_set::{DirMap, DirSet};
+pub use self::pose::Pose;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Dir {
+    North,
+    East,
+    South,
+    West,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum OrthDir {
+    Horizontal,
+    Vertical,
+}
+
+/// A two sided mirror set diagonally in a grid cell
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Mirror {
+    /// `/`
+    Slash,
+
+    /// `\`
+    Backslash,
+}
+
+impl TryFrom<char> for Mirror {
+    type Error = String;
+
+    fn try_from(c: char) -> Result<Self, Self::Error> {
+        match c {
+            '/' => Ok(Mirror::Slash),
+            '\\' => Ok(Mirror::Backslash),
+            _ => Err(format!("{c:?} is not a mirror")),
+        }
+    }
+}
+
+/// A change of direction relative to the current heading
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Turn {
+    Left,
+    Right,
+    Straight,
+    Back,
+}
+
+impl Turn {
+    /// Every turn, in order of clockwise quarter turns from straight ahead
+    pub const ALL: [Turn; 4] = [Turn::Straight, Turn::Right, Turn::Back, Turn::Left];
+
+    /// The number of clockwise quarter turns this makes (0 to 3)
+    pub fn quarter_turns(&self) -> usize {
+        Self::ALL.iter().position(|t| t == self).unwrap()
+    }
+
+    /// The turn that undoes this one
+    pub fn reverse(&self) -> Self {
+        Self::ALL[(4 - self.quarter_turns()) % 4]
+    }
+}
+
+/// The ways puzzle text writes directions
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Notation {
+    /// `U`, `R`, `D`, `L`
+    Udlr,
+
+    /// `N`, `E`, `S`, `W`
+    Compass,
+
+    /// `^`, `>`, `v`, `<`
+    Arrow,
+
+    /// `up`, `right`, `down`, `left`
+    UdlrWord,
+
+    /// `north`, `east`, `south`, `west`
+    CompassWord,
+}
+
+impl Notation {
+    pub const ALL: [Notation; 5] = [
+        Notation::Udlr,
+        Notation::Compass,
+        Notation::Arrow,
+        Notation::UdlrWord,
+        Notation::CompassWord,
+    ];
+
+    /// How each direction is written, in the same order as [`Dir::ALL`]
+    fn symbols(&self) -> [&'static str; 4] {
+        match self {
+            Notation::Udlr => ["U", "R", "D", "L"],
+            Notation::Compass => ["N", "E", "S", "W"],
+            Notation::Arrow => ["^", ">", "v", "<"],
+            Notation::UdlrWord => ["up", "right", "down", "left"],
+            Notation::CompassWord => ["north", "east", "south", "west"],
+        }
+    }
+}
+
+/// A direction formatted in a particular notation, see [`Dir::display`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct DirDisplay(Dir, Notation);
+
+impl std::fmt::Display for DirDisplay {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let DirDisplay(dir, notation) = self;
+        write!(f, "{}", notation.symbols()[dir.index()])
+    }
+}
+
+/// Formats as an arrow, e.g `^`
+impl std::fmt::Display for Dir {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.display(Notation::Arrow))
+    }
+}
+
+impl Dir {
+    /// Every direction, clockwise from north
+    pub const ALL: [Dir; 4] = [Dir::North, Dir::East, Dir::South, Dir::West];
+
+    fn index(&self) -> usize {
+        Self::ALL.iter().position(|d| d == self).unwrap()
+    }
+
+    /// Parse a direction written in a particular notation (ignoring case)
+    ///
+    /// # Examples
+    /// ```
+    /// use aoc::direction::{Dir, Notation};
+    /// assert_eq!(Dir::parse("L", Notation::Udlr), Ok(Dir::West));
+    /// assert_eq!(Dir::parse("North", Notation::CompassWord), Ok(Dir::North));
+    /// assert!(Dir::parse("L", Notation::Compass).is_err());
+    /// ```
+    pub fn parse(s: &str, notation: Notation) -> Result<Self, String> {
+        notation
+            .symbols()
+            .iter()
+            .position(|symbol| symbol.eq_ignore_ascii_case(s))
+            .map(|i| Self::ALL[i])
+            .ok_or_else(|| format!("{s:?} is not a direction in {notation:?} notation"))
+    }
+
+    /// Parse a direction written as a single character in a particular notation
+    ///
+    /// # Examples
+    /// ```
+    /// use aoc::direction::{Dir, Notation};
+    /// assert_eq!(Dir::from_char('v', Notation::Arrow), Ok(Dir::South));
+    /// assert_eq!(Dir::from_char('e', Notation::Compass), Ok(Dir::East));
+    /// ```
+    pub fn from_char(c: char, notation: Notation) -> Result<Self, String> {
+        Self::parse(c.encode_utf8(&mut [0; 4]), notation)
+    }
+
+    /// Format the direction in a particular notation
+    ///
+    /// # Examples
+    /// ```
+    /// use aoc::direction::{Dir, Notation};
+    /// assert_eq!(Dir::West.display(Notation::Udlr).to_string(), "L");
+    /// assert_eq!(Dir::West.display(Notation::CompassWord).to_string(), "west");
+    /// assert_eq!(Dir::West.to_string(), "<");
+    /// ```
+    pub fn display(&self, notation: Notation) -> DirDisplay {
+        DirDisplay(*self, notation)
+    }
+
+    /// Rotate clockwise by some number of quarter turns (anticlockwise if negative)
+    ///
+    /// # Examples
+    /// ```
+    /// use aoc::direction::Dir;
+    /// assert_eq!(Dir::North.rotate(1), Dir::East);
+    /// assert_eq!(Dir::North.rotate(-1), Dir::West);
+    /// assert_eq!(Dir::East.rotate(6), Dir::West);
+    /// ```
+    pub fn rotate(&self, quarter_turns: isize) -> Self {
+        Self::ALL[(self.index() as isize + quarter_turns).rem_euclid(4) as usize]
+    }
+
+    /// The direction faced after making a turn
+    ///
+    /// # Examples
+    /// ```
+    /// use aoc::direction::{Dir, Turn};
+    /// assert_eq!(Dir::North.apply(Turn::Left), Dir::West);
+    /// assert_eq!(Dir::North.apply(Turn::Back), Dir::South);
+    /// ```
+    pub fn apply(&self, turn: Turn) -> Self {
+        self.rotate(turn.quarter_turns() as isize)
+    }
+
+    /// The turn needed to face another direction
+    ///
+    /// # Examples
+    /// ```
+    /// use aoc::direction::{Dir, Turn};
+    /// assert_eq!(Dir::North.turn_to(Dir::East), Turn::Right);
+    /// assert_eq!(Dir::West.turn_to(Dir::East), Turn::Back);
+    /// assert_eq!(Dir::South.turn_to(Dir::South), Turn::Straight);
+    /// ```
+    pub fn turn_to(&self, other: Dir) -> Turn {
+        Turn::ALL[(other.index() + 4 - self.index()) % 4]
+    }
+
+    /// The direction a beam travels after bouncing off a mirror
+    ///
+    /// # Examples
+    /// ```
+    /// use aoc::direction::{Dir, Mirror};
+    /// assert_eq!(Dir::East.reflect(Mirror::Slash), Dir::North);
+    /// assert_eq!(Dir::East.reflect(Mirror::Backslash), Dir::South);
+    /// ```
+    pub fn reflect(&self, mirror: Mirror) -> Self {
+        match (mirror, self.orthogonal()) {
+            (Mirror::Slash, OrthDir::Horizontal) => self.turn_left(),
+            (Mirror::Slash, OrthDir::Vertical) => self.turn_right(),
+            (Mirror::Backslash, OrthDir::Horizontal) => self.turn_right(),
+            (Mirror::Backslash, OrthDir::Vertical) => self.turn_left(),
+        }
+    }
+
+    pub fn orthogonal(&self) -> OrthDir {
+        use Dir::*;
+        use OrthDir::*;
+        match self {
+            North => Vertical,
+            South => Vertical,
+            East => Horizontal,
+            West => Horizontal,
+        }
+    }
+
+    pub fn opposite(&self) -> Self {
+        use Dir::*;
+        match self {
+            North => South,
+            South => North,
+            East => West,
+            West => East,
+        }
+    }
+
+    pub fn turn_right(&self) -> Self {
+        use Dir::*;
+        match self {
+            North => East,
+            South => West,
+            East => South,
+            West => North,
+        }
+    }
+
+    pub fn turn_left(&self) -> Self {
+        use Dir::*;
+        match self {
+            North => West,
+            South => East,
+            East => North,
+            West => South,
+        }
+    }
+}
+
+impl OrthDir {
+    /// The two directions along this axis
+    ///
+    /// # Examples
+    /// ```
+    /// use aoc::direction::{Dir, OrthDir};
+    /// assert_eq!(OrthDir::Vertical.dirs(), [Dir::North, Dir::South]);
+    /// ```
+    pub fn dirs(&self) -> [Dir; 2] {
+        match self {
+            OrthDir::Horizontal => [Dir::East, Dir::West],
+            OrthDir::Vertical => [Dir::North, Dir::South],
+        }
+    }
+
+    /// The directions a beam leaves in after hitting a splitter lying along this axis
+    /// Beams travelling along the splitter pass straight through, and any others split both
+    /// ways along it.
+    ///
+    /// # Examples
+    /// ```
+    /// use aoc::direction::{Dir, OrthDir};
+    /// assert_eq!(OrthDir::Vertical.split(Dir::North).as_slice(), [Dir::North]);
+    /// assert_eq!(OrthDir::Vertical.split(Dir::East).as_slice(), [Dir::North, Dir::South]);
+    /// ```
+    pub fn split(&self, dir: Dir) -> SmallVec<[Dir; 2]> {
+        if dir.orthogonal() == *self {
+            smallvec![dir]
+        } else {
+            SmallVec::from(self.dirs())
+        }
+    }
+}
+
+/// A compass direction including the diagonals
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Dir8 {
+    North,
+    NorthEast,
+    East,
+    SouthEast,
+    South,
+    SouthWest,
+    West,
+    NorthWest,
+}
+
+impl Dir8 {
+    /// Every direction, clockwise from north
+    pub const ALL: [Dir8; 8] = [
+        Dir8::North,
+        Dir8::NorthEast,
+        Dir8::East,
+        Dir8::SouthEast,
+        Dir8::South,
+        Dir8::SouthWest,
+        Dir8::West,
+        Dir8::NorthWest,
+    ];
+
+    /// Rotate clockwise by some number of eighth turns
+    fn rotate(&self, eighths: usize) -> Self {
+        let index = Self::ALL.iter().position(|d| d == self).unwrap();
+        Self::ALL[(index + eighths) % 8]
+    }
+
+    /// Whether this is one of the diagonal directions
+    pub fn is_diagonal(&self) -> bool {
+        Dir::try_from(*self).is_err()
+    }
+
+    pub fn opposite(&self) -> Self {
+        self.rotate(4)
+    }
+
+    /// Turn 90° clockwise
+    pub fn turn_right(&self) -> Self {
+        self.rotate(2)
+    }
+
+    /// Turn 90° anticlockwise
+    pub fn turn_left(&self) -> Self {
+        self.rotate(6)
+    }
+
+    /// Turn 45° clockwise
+    ///
+    /// # Examples
+    /// ```
+    /// use aoc::direction::Dir8;
+    /// assert_eq!(Dir8::North.turn_right_45(), Dir8::NorthEast);
+    /// assert_eq!(Dir8::NorthWest.turn_right_45(), Dir8::North);
+    /// ```
+    pub fn turn_right_45(&self) -> Self {
+        self.rotate(1)
+    }
+
+    /// Turn 45° anticlockwise
+    pub fn turn_left_45(&self) -> Self {
+        self.rotate(7)
+    }
+}
+
+impl From<Dir> for Dir8 {
+    fn from(value: Dir) -> Self {
+        match value {
+            Dir::North => Dir8::North,
+            Dir::East => Dir8::East,
+            Dir::South => Dir8::South,
+            Dir::West => Dir8::West,
+        }
+    }
+}
+
+impl TryFrom<Dir8> for Dir {
+    type Error = String;
+
+    fn try_from(value: Dir8) -> Result<Self, Self::Error> {
+        match value {
+            Dir8::North => Ok(Dir::North),
+            Dir8::East => Ok(Dir::East),
+            Dir8::South => Ok(Dir::South),
+            Dir8::West => Ok(Dir::West),
+            d => Err(format!("Dir cannot represent diagonal {d:?}")),
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn notations_round_trip() {
+        for notation in Notation::ALL {
+            for dir in Dir::ALL {
+                let text = dir.display(notation).to_string();
+                assert_eq!(Dir::parse(&text, notation), Ok(dir));
+                assert_eq!(Dir::parse(&text.to_uppercase(), notation), Ok(dir));
+                if let [c] = text.chars().collect::<Vec<_>>()[..] {
+                    assert_eq!(Dir::from_char(c, notation), Ok(dir));
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn single_character_notations_are_distinct() {
+        let chars: Vec<_> = [Notation::Udlr, Notation::Compass, Notation::Arrow]
+            .into_iter()
+            .flat_map(|n| Dir::ALL.map(|d| d.display(n).to_string()))
+            .collect();
+        for (i, c) in chars.iter().enumerate() {
+            assert!(!chars[i + 1..]
+                .iter()
+                .any(|other| other.eq_ignore_ascii_case(c)));
+        }
+    }
+
+    #[test]
+    fn turns() {
+        for dir in Dir::ALL {
+            assert_eq!(dir.apply(Turn::Right), dir.turn_right());
+            assert_eq!(dir.apply(Turn::Left), dir.turn_left());
+            assert_eq!(dir.apply(Turn::Back), dir.opposite());
+            assert!(dir.orthogonal().dirs().contains(&dir));
+            for turn in Turn::ALL {
+                assert_eq!(dir.turn_to(dir.apply(turn)), turn);
+                assert_eq!(dir.apply(turn).apply(turn.reverse()), dir);
+            }
+            for other in Dir::ALL {
+                assert_eq!(dir.apply(dir.turn_to(other)), other);
+            }
+        }
+    }
+
+    #[test]
+    fn optics() {
+        for mirror in [Mirror::Slash, Mirror::Backslash] {
+            for dir in Dir::ALL {
+                let reflected = dir.reflect(mirror);
+                assert_eq!(reflected.orthogonal(), dir.turn_right().orthogonal());
+                // Light paths are reversible
+                assert_eq!(reflected.opposite().reflect(mirror), dir.opposite());
+            }
+        }
+        assert_eq!(Mirror::try_from('\\'), Ok(Mirror::Backslash));
+        assert!(Mirror::try_from('|').is_err());
+
+        for axis in [OrthDir::Horizontal, OrthDir::Vertical] {
+            for dir in Dir::ALL {
+                let split = axis.split(dir);
+                assert!(split.contains(&dir) || split.len() == 2);
+                assert!(!split.contains(&dir.opposite()));
+            }
+        }
+    }
+
+    #[test]
+    fn unknown_directions() {
+        assert!(Dir::parse("", Notation::Udlr).is_err());
+        assert!(Dir::parse("upp", Notation::UdlrWord).is_err());
+        assert!(Dir::from_char('x', Notation::Arrow).is_err());
+        assert!(Dir::from_char('u', Notation::UdlrWord).is_err());
+    }
+}
@@ -0,0 +1,122 @@
+use super::{Dir, Turn};
+use crate::grid::GridPos;
+
+/// A position and the direction being faced there, such as a walker or a beam of light
+///
+/// Poses made with [`Pose::counting`] also count the steps taken since the last time the
+/// heading changed in `run`, for puzzles that limit how far you can go in a straight line.
+/// Poses made with [`Pose::new`] leave `run` as None, so two poses are equal whenever they
+/// share a position and direction.
+///
+/// # Examples
+/// ```
+/// use aoc::{pos, direction::{Dir, Pose, Turn}, grid::GridPos};
+/// let pose = Pose::counting(pos!(0, 0), Dir::East).advance(3).turn(Turn::Right).step();
+/// assert_eq!((pose.pos, pose.dir, pose.run), (pos!(3, 1), Dir::South, Some(1)));
+///
+/// let pose = Pose::new(pos!(0, 0), Dir::East).advance(3);
+/// assert_eq!(pose.run, None);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Pose {
+    pub pos: GridPos,
+    pub dir: Dir,
+    pub run: Option<usize>,
+}
+
+impl Pose {
+    /// A pose that doesn't count its run length
+    pub fn new(pos: GridPos, dir: Dir) -> Self {
+        Self {
+            pos,
+            dir,
+            run: None,
+        }
+    }
+
+    /// A pose that counts its run length, starting from zero
+    pub fn counting(pos: GridPos, dir: Dir) -> Self {
+        Self {
+            pos,
+            dir,
+            run: Some(0),
+        }
+    }
+
+    /// Move forwards some number of steps
+    pub fn advance(&self, n: usize) -> Self {
+        Self {
+            pos: self.pos.step(self.dir, n as isize),
+            run: self.run.map(|run| run + n),
+            ..*self
+        }
+    }
+
+    /// Move forwards a single step
+    pub fn step(&self) -> Self {
+        self.advance(1)
+    }
+
+    /// Turn without moving, which starts a new run (if counting) unless the turn is `Straight`
+    pub fn turn(&self, turn: Turn) -> Self {
+        Self {
+            dir: self.dir.apply(turn),
+            run: if turn == Turn::Straight {
+                self.run
+            } else {
+                self.run.map(|_| 0)
+            },
+            ..*self
+        }
+    }
+
+    /// Turn to face a direction without moving
+    pub fn face(&self, dir: Dir) -> Self {
+        self.turn(self.dir.turn_to(dir))
+    }
+
+    /// The position one step ahead
+    pub fn ahead(&self) -> GridPos {
+        self.step().pos
+    }
+}
+
+impl From<(GridPos, Dir)> for Pose {
+    fn from((pos, dir): (GridPos, Dir)) -> Self {
+        Self::new(pos, dir)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::pos;
+
+    #[test]
+    fn turning_resets_run() {
+        let pose = Pose::counting(pos!(1, 1), Dir::North).advance(2);
+        assert_eq!(pose.run, Some(2));
+        assert_eq!(pose.turn(Turn::Straight).step().run, Some(3));
+        assert_eq!(pose.turn(Turn::Back).run, Some(0));
+
+        // Four left turns and steps make a loop
+        let square = (0..4).fold(pose, |p, _| p.turn(Turn::Left).step());
+        assert_eq!((square.pos, square.dir), (pose.pos, pose.dir));
+        assert_eq!(square.ahead(), pos!(1, -2));
+    }
+
+    #[test]
+    fn uncounted_poses_dedup() {
+        use std::collections::HashSet;
+
+        // The same place reached by two different routes
+        let start = Pose::new(pos!(0, 0), Dir::East);
+        let straight = start.advance(2);
+        let detour = start.step().turn(Turn::Left).turn(Turn::Right).step();
+        assert_eq!(straight, detour);
+
+        let visited: HashSet<_> = [start, straight, detour, start.step().step()].into();
+        assert_eq!(visited.len(), 2);
+        assert_eq!(start.turn(Turn::Right).run, None);
+    }
+}
@@ -0,0 +1,406 @@
+//! Utilities for measuring closed loops of grid positions
+//!
+//! A loop is given as an ordered list of vertices, and is implicitly closed by an edge
+//! from the last vertex back to the first. The vertices can either be every tile along
+//! the loop or only its corners, both produce the same results.
+//!
+//! Any coordinate type can be used, and all arithmetic is done with checked `i128` operations,
+//! so loops with very large coordinates can be measured without ever allocating a grid.
+//! Each measurement is None if the loop is too large for its intermediate values to fit in an
+//! `i128`.
+//!
+//! # Examples
+//! ```
+//! use aoc::{geometry, pos, grid::GridPos};
+//!
+//! // A 3x3 square of tiles with a single tile inside
+//! let square = [pos!(0, 0), pos!(2, 0), pos!(2, 2), pos!(0, 2)];
+//! assert_eq!(geometry::polygon_area(&square), Some(4));
+//! assert_eq!(geometry::boundary_points(&square), Some(8));
+//! assert_eq!(geometry::interior_points(&square), Some(1));
+//! ```
+
+use crate::{
+    grid::{Coord, GridPos},
+    math::checked_gcd,
+};
+
+/// Where a point lies relative to a loop
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum PointLocation {
+    Inside,
+    Boundary,
+    Outside,
+}
+
+fn widen<T: Coord>(p: &GridPos<T>) -> (i128, i128) {
+    (p.0.as_i128(), p.1.as_i128())
+}
+
+/// The vector from one point to another, or None if it doesn't fit in an `i128`
+fn delta(from: (i128, i128), to: (i128, i128)) -> Option<(i128, i128)> {
+    Some((to.0.checked_sub(from.0)?, to.1.checked_sub(from.1)?))
+}
+
+/// The cross product of two vectors, or None if it doesn't fit in an `i128`
+fn cross(a: (i128, i128), b: (i128, i128)) -> Option<i128> {
+    a.0.checked_mul(b.1)?.checked_sub(a.1.checked_mul(b.0)?)
+}
+
+/// Iterate over each edge of a loop, including the closing edge
+/// with coordinates widened to `i128`
+fn edges<T: Coord>(
+    polygon: &[GridPos<T>],
+) -> impl Iterator<Item = ((i128, i128), (i128, i128))> + '_ {
+    polygon
+        .iter()
+        .zip(polygon.iter().cycle().skip(1))
+        .map(|(a, b)| (widen(a), widen(b)))
+}
+
+/// Twice the signed area enclosed by a loop (shoelace formula)
+/// Positive when the loop is clockwise in grid coordinates (y pointing down)
+///
+/// # Examples
+/// ```
+/// use aoc::{geometry::signed_double_area, pos, grid::GridPos};
+/// let far = i64::MAX as i128;
+/// let triangle = [pos!(0, 0; i128), pos!(4 * far, 0; i128), pos!(0, 4 * far; i128)];
+/// assert_eq!(signed_double_area(&triangle), None);
+/// ```
+pub fn signed_double_area<T: Coord>(polygon: &[GridPos<T>]) -> Option<i128> {
+    // Measure from the first vertex to keep the products small
+    let Some(origin) = polygon.first().map(widen) else {
+        return Some(0);
+    };
+    edges(polygon).try_fold(0i128, |total, (a, b)| {
+        total.checked_add(cross(delta(origin, a)?, delta(origin, b)?)?)
+    })
+}
+
+/// Twice the area enclosed by a loop
+/// This is always exact, unlike [`polygon_area`] which may have to round
+pub fn polygon_double_area<T: Coord>(polygon: &[GridPos<T>]) -> Option<u128> {
+    signed_double_area(polygon).map(i128::unsigned_abs)
+}
+
+/// The area enclosed by a loop, measured between vertex centres
+/// Loops with diagonal edges can have half integer areas, which are rounded down
+///
+/// # Examples
+/// ```
+/// use aoc::{geometry::polygon_area, pos, grid::GridPos};
+/// let triangle = [pos!(0, 0), pos!(4, 0), pos!(0, 3)];
+/// assert_eq!(polygon_area(&triangle), Some(6));
+///
+/// let huge = [pos!(0, 0), pos!(1_000_000_000, 0), pos!(1_000_000_000, 1_000_000_000), pos!(0, 1_000_000_000)];
+/// assert_eq!(polygon_area(&huge), Some(1_000_000_000_000_000_000));
+/// ```
+pub fn polygon_area<T: Coord>(polygon: &[GridPos<T>]) -> Option<u128> {
+    polygon_double_area(polygon).map(|area| area / 2)
+}
+
+/// The number of lattice points that lie on the edges of a loop
+///
+/// # Examples
+/// ```
+/// use aoc::{geometry::boundary_points, pos, grid::GridPos};
+/// let triangle = [pos!(0, 0), pos!(4, 0), pos!(0, 3)];
+/// assert_eq!(boundary_points(&triangle), Some(8));
+/// ```
+pub fn boundary_points<T: Coord>(polygon: &[GridPos<T>]) -> Option<u128> {
+    edges(polygon).try_fold(0u128, |total, (a, b)| {
+        let (dx, dy) = delta(a, b)?;
+        total.checked_add(checked_gcd(dx, dy)? as u128)
+    })
+}
+
+/// The number of lattice points strictly inside a loop (Pick's theorem)
+///
+/// # Examples
+/// ```
+/// use aoc::{geometry::interior_points, pos, grid::GridPos};
+/// let triangle = [pos!(0, 0), pos!(4, 0), pos!(0, 3)];
+/// assert_eq!(interior_points(&triangle), Some(3));
+///
+/// // Degenerate loops don't enclose anything
+/// assert_eq!(interior_points(&[pos!(0, 0), pos!(5, 0)]), Some(0));
+/// ```
+pub fn interior_points<T: Coord>(polygon: &[GridPos<T>]) -> Option<u128> {
+    // 2A = 2I + B - 2, which only holds for loops that enclose some area
+    let double_area = polygon_double_area(polygon)?;
+    if double_area == 0 {
+        return Some(0);
+    }
+    Some(
+        double_area
+            .checked_add(2)?
+            .saturating_sub(boundary_points(polygon)?)
+            / 2,
+    )
+}
+
+/// The number of distinct lattice points on a loop whose vertices all lie on one line
+/// Gives Some(None) if the vertices aren't all on one line, and None if the arithmetic
+/// overflows.
+fn collinear_points<T: Coord>(polygon: &[GridPos<T>]) -> Option<Option<u128>> {
+    let Some(start) = polygon.first().map(widen) else {
+        return Some(Some(0));
+    };
+    let Some(other) = polygon.iter().map(widen).find(|&p| p != start) else {
+        return Some(Some(1));
+    };
+
+    // Every lattice point on the line is start + t * step for some integer t
+    let (dx, dy) = delta(start, other)?;
+    let g = checked_gcd(dx, dy)?;
+    let step = (dx / g, dy / g);
+    let mut ts = Vec::with_capacity(polygon.len());
+    for p in polygon.iter().map(widen) {
+        let offset = delta(start, p)?;
+        if cross(step, offset)? != 0 {
+            return Some(None);
+        }
+        ts.push(if step.0 != 0 {
+            offset.0.checked_div(step.0)?
+        } else {
+            offset.1.checked_div(step.1)?
+        });
+    }
+
+    // The path is connected, so it covers every point between its ends
+    let (min, max) = (ts.iter().min()?, ts.iter().max()?);
+    Some(max.abs_diff(*min).checked_add(1))
+}
+
+/// The number of lattice points either inside or on the edges of a loop
+/// This is the number of tiles covered by a loop drawn on a grid, including the loop itself
+/// Loops that run back over themselves along a single line count each tile once.
+///
+/// # Examples
+/// ```
+/// use aoc::{geometry::enclosed_points, pos, grid::GridPos};
+/// let square = [pos!(0, 0), pos!(2, 0), pos!(2, 2), pos!(0, 2)];
+/// assert_eq!(enclosed_points(&square), Some(9));
+///
+/// // Out along a line and back again
+/// assert_eq!(enclosed_points(&[pos!(0, 0), pos!(5, 0)]), Some(6));
+/// ```
+pub fn enclosed_points<T: Coord>(polygon: &[GridPos<T>]) -> Option<u128> {
+    if let Some(count) = collinear_points(polygon)? {
+        return Some(count);
+    }
+    interior_points(polygon)?.checked_add(boundary_points(polygon)?)
+}
+
+/// Find where a point lies relative to a loop
+///
+/// # Examples
+/// ```
+/// use aoc::{geometry::{locate_point, PointLocation}, pos, grid::GridPos};
+/// let square = [pos!(0, 0), pos!(4, 0), pos!(4, 4), pos!(0, 4)];
+/// assert_eq!(locate_point(&square, pos!(2, 2)), Some(PointLocation::Inside));
+/// assert_eq!(locate_point(&square, pos!(4, 1)), Some(PointLocation::Boundary));
+/// assert_eq!(locate_point(&square, pos!(5, 2)), Some(PointLocation::Outside));
+/// ```
+pub fn locate_point<T: Coord>(polygon: &[GridPos<T>], point: GridPos<T>) -> Option<PointLocation> {
+    let p = widen(&point);
+    let mut inside = false;
+    for (a, b) in edges(polygon) {
+        // On this edge?
+        let cross = cross(delta(a, b)?, delta(a, p)?)?;
+        if cross == 0
+            && (a.0.min(b.0)..=a.0.max(b.0)).contains(&p.0)
+            && (a.1.min(b.1)..=a.1.max(b.1)).contains(&p.1)
+        {
+            return Some(PointLocation::Boundary);
+        }
+
+        // Does a ray cast in the +x direction cross this edge?
+        // The edge is treated as half open in y so shared vertices are only counted once
+        if (a.1 > p.1) != (b.1 > p.1) {
+            // Compare the point against the crossing x without dividing
+            let crosses = if b.1 > a.1 { cross > 0 } else { cross < 0 };
+            if crosses {
+                inside = !inside;
+            }
+        }
+    }
+
+    Some(if inside {
+        PointLocation::Inside
+    } else {
+        PointLocation::Outside
+    })
+}
+
+/// Whether a point lies strictly inside a loop
+pub fn polygon_contains<T: Coord>(polygon: &[GridPos<T>], point: GridPos<T>) -> Option<bool> {
+    locate_point(polygon, point).map(|location| location == PointLocation::Inside)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::pos;
+    use itertools::Itertools;
+    use proptest::prelude::*;
+
+    /// Count points by testing every position in the bounding box
+    fn brute_force(polygon: &[GridPos]) -> (u128, u128) {
+        let (min_x, max_x) = polygon.iter().map(|p| p.0).minmax().into_option().unwrap();
+        let (min_y, max_y) = polygon.iter().map(|p| p.1).minmax().into_option().unwrap();
+        (min_x..=max_x)
+            .cartesian_product(min_y..=max_y)
+            .map(|(x, y)| locate_point(polygon, pos!(x, y)).unwrap())
+            .fold((0, 0), |(inside, boundary), loc| match loc {
+                PointLocation::Inside => (inside + 1, boundary),
+                PointLocation::Boundary => (inside, boundary + 1),
+                PointLocation::Outside => (inside, boundary),
+            })
+    }
+
+    #[test]
+    fn tile_loop_matches_corner_loop() {
+        // Every tile around the edge of a 4x3 rectangle, anticlockwise
+        let tiles = [
+            pos!(0, 0),
+            pos!(0, 1),
+            pos!(0, 2),
+            pos!(1, 2),
+            pos!(2, 2),
+            pos!(3, 2),
+            pos!(3, 1),
+            pos!(3, 0),
+            pos!(2, 0),
+            pos!(1, 0),
+        ];
+        let corners = [pos!(0, 0), pos!(3, 0), pos!(3, 2), pos!(0, 2)];
+        assert_eq!(polygon_area(&tiles), polygon_area(&corners));
+        assert_eq!(boundary_points(&tiles), Some(10));
+        assert_eq!(interior_points(&tiles), Some(2));
+        assert_eq!(
+            signed_double_area(&tiles),
+            signed_double_area(&corners).map(|a| -a)
+        );
+    }
+
+    #[test]
+    fn degenerate_loops() {
+        assert_eq!(interior_points::<isize>(&[]), Some(0));
+        assert_eq!(enclosed_points::<isize>(&[]), Some(0));
+        assert_eq!(interior_points(&[pos!(3, 3)]), Some(0));
+        assert_eq!(enclosed_points(&[pos!(3, 3)]), Some(1));
+        assert_eq!(enclosed_points(&[pos!(3, 3), pos!(3, 3)]), Some(1));
+        assert_eq!(
+            interior_points(&[pos!(0, 0), pos!(5, 0), pos!(2, 0)]),
+            Some(0)
+        );
+        assert_eq!(enclosed_points(&[pos!(0, 0), pos!(5, 0)]), Some(6));
+        assert_eq!(
+            enclosed_points(&[pos!(2, 0), pos!(5, 0), pos!(-1, 0)]),
+            Some(7)
+        );
+        assert_eq!(
+            enclosed_points(&[pos!(0, 0), pos!(4, 2), pos!(2, 1)]),
+            Some(3)
+        );
+        assert_eq!(enclosed_points(&[pos!(1, 1), pos!(1, -3)]), Some(5));
+    }
+
+    #[test]
+    fn wide_coordinates() {
+        // A dig plan style loop whose area doesn't fit in an i64
+        let size = 4_000_000_000_000_000_000i128;
+        let square = [
+            pos!(0, 0; i128),
+            pos!(size, 0; i128),
+            pos!(size, size; i128),
+            pos!(0, size; i128),
+        ];
+        assert_eq!(boundary_points(&square), Some(4 * size as u128));
+        assert_eq!(interior_points(&square), Some((size as u128 - 1).pow(2)));
+        assert_eq!(
+            locate_point(&square, pos!(size - 1, 1; i128)),
+            Some(PointLocation::Inside)
+        );
+
+        let small = [pos!(0, 0; i8), pos!(4, 0; i8), pos!(0, 3; i8)];
+        assert_eq!(polygon_area(&small), Some(6));
+
+        // A small loop a long way from the origin
+        let far = i128::MAX - 10;
+        let offset = [
+            pos!(far, far; i128),
+            pos!(far + 4, far; i128),
+            pos!(far, far + 3; i128),
+        ];
+        assert_eq!(polygon_area(&offset), Some(6));
+        assert_eq!(enclosed_points(&offset), Some(11));
+    }
+
+    #[test]
+    fn overflow() {
+        let far = i64::MAX as i128;
+        let triangle = [
+            pos!(0, 0; i128),
+            pos!(4 * far, 0; i128),
+            pos!(0, 4 * far; i128),
+        ];
+        assert_eq!(signed_double_area(&triangle), None);
+        assert_eq!(interior_points(&triangle), None);
+        assert_eq!(enclosed_points(&triangle), None);
+        assert_eq!(locate_point(&triangle, pos!(1, 1; i128)), None);
+        assert_eq!(polygon_contains(&triangle, pos!(1, 1; i128)), None);
+
+        let wide = [pos!(i128::MIN, 0; i128), pos!(i128::MAX, 0; i128)];
+        assert_eq!(boundary_points(&wide), None);
+        assert_eq!(enclosed_points(&wide), None);
+    }
+
+    #[test]
+    fn concave_loop() {
+        // A U shape, with the notch cut into the top
+        let u = [
+            pos!(0, 0),
+            pos!(2, 0),
+            pos!(2, 3),
+            pos!(4, 3),
+            pos!(4, 0),
+            pos!(6, 0),
+            pos!(6, 5),
+            pos!(0, 5),
+        ];
+        let counts = (interior_points(&u).unwrap(), boundary_points(&u).unwrap());
+        assert_eq!(brute_force(&u), counts);
+        assert_eq!(locate_point(&u, pos!(3, 1)), Some(PointLocation::Outside));
+        assert_eq!(locate_point(&u, pos!(3, 4)), Some(PointLocation::Inside));
+    }
+
+    proptest! {
+        #[test]
+        fn picks_theorem_matches_brute_force(
+            x in -20..20isize,
+            y in -20..20isize,
+            w in 1..10isize,
+            h in 1..10isize,
+            apex in 0..10isize,
+        ) {
+            // A pentagon with a sloped roof
+            let polygon = [pos!(x, y), pos!(x + w, y), pos!(x + w, y + h), pos!(x + apex, y + h + apex), pos!(x, y + h)];
+            let counts = (interior_points(&polygon).unwrap(), boundary_points(&polygon).unwrap());
+            prop_assert_eq!(brute_force(&polygon), counts);
+        }
+
+        #[test]
+        fn flat_loops_match_brute_force(
+            dx in -3..3isize,
+            dy in -3..3isize,
+            ts in prop::collection::vec(-5..5isize, 1..5),
+        ) {
+            let polygon: Vec<_> = ts.iter().map(|&t| pos!(t * dx, t * dy)).collect();
+            let (inside, boundary) = brute_force(&polygon);
+            prop_assert_eq!(enclosed_points(&polygon), Some(inside + boundary));
+        }
+    }
+}
@@ -0,0 +1,247 @@
+use itertools::Itertools;
+
+use super::{Grid, GridPos};
+use crate::pos;
+
+/// A grid where every row and column stands in for a run of rows or columns in a much
+/// larger "real" grid
+///
+/// Each compressed column has a width and each compressed row has a height, so a cell covers
+/// a whole rectangle of real positions. Only the distinct rows and columns are ever stored,
+/// which makes it possible to work with inputs whose coordinates are in the millions.
+///
+/// # Examples
+/// ```
+/// use aoc::{pos, grid::{CompressedGrid, Grid, GridPos}};
+///
+/// // The middle column and row are each a million units wide
+/// let grid = Grid::new(vec![vec!['#', '.', '.'], vec!['.', '.', '.'], vec!['.', '.', '#']]);
+/// let grid = CompressedGrid::from_weights(grid, vec![1, 1_000_000, 1], vec![1, 1_000_000, 1]);
+/// assert_eq!(grid.distance(pos!(0, 0), pos!(2, 2)), 2_000_002);
+/// assert_eq!(grid.real_width(), 1_000_002);
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CompressedGrid<T> {
+    pub cells: Grid<T>,
+    origin: GridPos,
+    col_starts: Vec<usize>,
+    row_starts: Vec<usize>,
+}
+
+/// Turn a list of weights into the offset of each one from the start, with a final entry
+/// for the total
+fn prefix_offsets(weights: &[usize]) -> Vec<usize> {
+    std::iter::once(0)
+        .chain(weights.iter().scan(0, |total, w| {
+            *total += w;
+            Some(*total)
+        }))
+        .collect()
+}
+
+/// Split a sorted, deduplicated list of coordinates into runs that each either contain a
+/// single coordinate, or cover a gap between two coordinates
+fn coordinate_weights(coords: &[isize]) -> Vec<usize> {
+    coords
+        .iter()
+        .tuple_windows()
+        .flat_map(|(&a, &b)| {
+            let gap = (b - a - 1) as usize;
+            std::iter::once(1).chain((gap > 0).then_some(gap))
+        })
+        .chain((!coords.is_empty()).then_some(1))
+        .collect()
+}
+
+impl<T> CompressedGrid<T> {
+    /// Create a compressed grid from a grid of cells and the real width of each column and
+    /// height of each row
+    /// panics if the number of weights doesn't match the grid
+    pub fn from_weights(cells: Grid<T>, col_weights: Vec<usize>, row_weights: Vec<usize>) -> Self {
+        assert_eq!(cells.width, col_weights.len(), "One weight per column");
+        assert_eq!(cells.height, row_weights.len(), "One weight per row");
+        Self {
+            cells,
+            origin: pos!(0, 0),
+            col_starts: prefix_offsets(&col_weights),
+            row_starts: prefix_offsets(&row_weights),
+        }
+    }
+
+    /// Create a compressed grid covering a sparse set of real x and y coordinates
+    ///
+    /// Every distinct coordinate gets its own row or column with a weight of one, and each gap
+    /// between coordinates is collapsed into a single row or column. Cells are filled by calling
+    /// the provided function with the real position of their top left corner.
+    ///
+    /// # Examples
+    /// ```
+    /// use aoc::{pos, grid::{CompressedGrid, GridPos}};
+    /// let grid = CompressedGrid::from_coords_fn([0, 10, 1_000_000], [-5, 5], |p| p);
+    ///
+    /// // Columns are 0, 1..=9, 10, 11..=999_999, 1_000_000
+    /// assert_eq!((grid.cells.width, grid.cells.height), (5, 3));
+    /// assert_eq!(grid.compress(pos!(500, 0)), Some(pos!(3, 1)));
+    /// assert_eq!(grid.cells.get(pos!(3, 1)), Some(pos!(11, -4)));
+    /// assert_eq!(grid.cell_size(pos!(3, 1)), (999_989, 9));
+    /// ```
+    pub fn from_coords_fn<X, Y, F>(xs: X, ys: Y, cell_fn: F) -> Self
+    where
+        X: IntoIterator<Item = isize>,
+        Y: IntoIterator<Item = isize>,
+        F: Fn(GridPos) -> T,
+    {
+        let xs = xs.into_iter().sorted().dedup().collect_vec();
+        let ys = ys.into_iter().sorted().dedup().collect_vec();
+        let origin = pos!(
+            xs.first().copied().unwrap_or(0),
+            ys.first().copied().unwrap_or(0)
+        );
+        let col_starts = prefix_offsets(&coordinate_weights(&xs));
+        let row_starts = prefix_offsets(&coordinate_weights(&ys));
+        let cells = Grid::from_fn(col_starts.len() - 1, row_starts.len() - 1, |p| {
+            cell_fn(pos!(
+                origin.0 + col_starts[p.0 as usize] as isize,
+                origin.1 + row_starts[p.1 as usize] as isize
+            ))
+        });
+
+        Self {
+            cells,
+            origin,
+            col_starts,
+            row_starts,
+        }
+    }
+
+    /// Create a compressed grid covering a sparse set of real x and y coordinates and fill it
+    /// with the default cell value
+    pub fn from_coords<X, Y>(xs: X, ys: Y) -> Self
+    where
+        T: Default,
+        X: IntoIterator<Item = isize>,
+        Y: IntoIterator<Item = isize>,
+    {
+        Self::from_coords_fn(xs, ys, |_| Default::default())
+    }
+
+    /// The total width of the grid in real units
+    pub fn real_width(&self) -> usize {
+        *self.col_starts.last().unwrap()
+    }
+
+    /// The total height of the grid in real units
+    pub fn real_height(&self) -> usize {
+        *self.row_starts.last().unwrap()
+    }
+
+    /// Find the compressed cell that contains a real position
+    /// Returns None when the position is outside of the grid
+    pub fn compress(&self, real: GridPos) -> Option<GridPos> {
+        let find = |starts: &[usize], offset: isize| {
+            let offset = usize::try_from(offset).ok()?;
+            (offset < *starts.last().unwrap())
+                .then(|| starts.partition_point(|&start| start <= offset) - 1)
+        };
+        Some(pos!(
+            find(&self.col_starts, real.0 - self.origin.0)?,
+            find(&self.row_starts, real.1 - self.origin.1)?
+        ))
+    }
+
+    /// The real position of the top left corner of a compressed cell
+    /// panics if the cell is out of bounds
+    pub fn expand(&self, cell: GridPos) -> GridPos {
+        pos!(
+            self.origin.0 + self.col_starts[cell.0 as usize] as isize,
+            self.origin.1 + self.row_starts[cell.1 as usize] as isize
+        )
+    }
+
+    /// The real width and height of a compressed cell
+    /// panics if the cell is out of bounds
+    pub fn cell_size(&self, cell: GridPos) -> (usize, usize) {
+        let (x, y) = (cell.0 as usize, cell.1 as usize);
+        (
+            self.col_starts[x + 1] - self.col_starts[x],
+            self.row_starts[y + 1] - self.row_starts[y],
+        )
+    }
+
+    /// The number of real positions covered by a compressed cell
+    /// panics if the cell is out of bounds
+    pub fn cell_area(&self, cell: GridPos) -> usize {
+        let (width, height) = self.cell_size(cell);
+        width * height
+    }
+
+    /// The manhattan distance in real units between the top left corners of two compressed cells
+    /// panics if either cell is out of bounds
+    pub fn distance(&self, from: GridPos, to: GridPos) -> usize {
+        self.expand(from).manhattan(self.expand(to))
+    }
+
+    /// The number of real positions covered by every cell that matches a predicate
+    ///
+    /// # Examples
+    /// ```
+    /// use aoc::grid::CompressedGrid;
+    /// let grid = CompressedGrid::from_coords_fn([0, 100], [0, 100], |p| {
+    ///     (1..100).contains(&p.0) && (1..100).contains(&p.1)
+    /// });
+    /// assert_eq!(grid.area_where(|_, &inside| inside), 99 * 99);
+    /// ```
+    pub fn area_where<F>(&self, predicate: F) -> usize
+    where
+        F: Fn(GridPos, &T) -> bool,
+    {
+        self.cells
+            .positions()
+            .zip(self.cells.cells_iter())
+            .filter(|&(p, cell)| predicate(p, cell))
+            .map(|(p, _)| self.cell_area(p))
+            .sum()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use proptest::prelude::*;
+
+    proptest! {
+        #[test]
+        fn compress_finds_containing_cell(
+            xs in prop::collection::vec(-50..50isize, 1..8),
+            ys in prop::collection::vec(-50..50isize, 1..8),
+        ) {
+            let grid: CompressedGrid<()> = CompressedGrid::from_coords(xs.clone(), ys.clone());
+            let (min_x, max_x) = xs.iter().copied().minmax().into_option().unwrap();
+            let (min_y, max_y) = ys.iter().copied().minmax().into_option().unwrap();
+            prop_assert_eq!(grid.real_width() as isize, max_x - min_x + 1);
+            prop_assert_eq!(grid.real_height() as isize, max_y - min_y + 1);
+
+            for (x, y) in (min_x - 1..=max_x + 1).cartesian_product(min_y - 1..=max_y + 1) {
+                let real = pos!(x, y);
+                let in_bounds = (min_x..=max_x).contains(&x) && (min_y..=max_y).contains(&y);
+                match grid.compress(real) {
+                    Some(cell) => {
+                        prop_assert!(in_bounds);
+                        let corner = grid.expand(cell);
+                        let (width, height) = grid.cell_size(cell);
+                        prop_assert!((corner.0..corner.0 + width as isize).contains(&x));
+                        prop_assert!((corner.1..corner.1 + height as isize).contains(&y));
+                    }
+                    None => prop_assert!(!in_bounds),
+                }
+            }
+
+            // Each listed coordinate is a unit cell
+            for (&x, &y) in xs.iter().cartesian_product(&ys) {
+                let cell = grid.compress(pos!(x, y)).unwrap();
+                prop_assert_eq!(grid.expand(cell), pos!(x, y));
+                prop_assert_eq!(grid.cell_area(cell), 1);
+            }
+        }
+    }
+}
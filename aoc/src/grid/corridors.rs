@@ -0,0 +1,146 @@
+use std::collections::{HashMap, HashSet};
+
+use super::{Grid, GridPos};
+
+/// A corridor of single width cells that connects two nodes
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Corridor {
+    pub from: GridPos,
+    pub to: GridPos,
+
+    /// The number of steps it takes to get from `from` to `to`
+    pub length: usize,
+
+    /// The cells between the two nodes, in walking order
+    pub cells: Vec<GridPos>,
+}
+
+/// A maze that has been collapsed into a graph of nodes connected by corridors
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CorridorGraph {
+    /// Every node in row-col order
+    pub nodes: Vec<GridPos>,
+
+    /// The corridors leaving each node
+    pub edges: HashMap<GridPos, Vec<Corridor>>,
+}
+
+impl CorridorGraph {
+    /// Get the corridors that can be walked from a node
+    pub fn edges_from(&self, node: GridPos) -> impl Iterator<Item = &Corridor> {
+        self.edges.get(&node).into_iter().flatten()
+    }
+}
+
+/// Collapse the corridors of a maze into a graph of junctions
+///
+/// `passable` decides whether a single step between two neighbouring cells is allowed,
+/// which can be used for walls as well as one way tiles. It should refuse any step into or
+/// out of a wall. Nodes are every cell accepted by
+/// `is_node` (e.g. the start and end of the maze) along with every junction, which is any cell
+/// connected to three or more of its neighbours. Corridors that lead to a dead end are dropped.
+///
+/// # Examples
+/// ```
+/// use aoc::{pos, grid::{compact_graph, Grid, GridPos}};
+/// let maze = Grid::new(vec![
+///     "#.#####".chars().collect(),
+///     "#.....#".chars().collect(),
+///     "#.###.#".chars().collect(),
+///     "#.....#".chars().collect(),
+///     "#####.#".chars().collect(),
+/// ]);
+/// let open = |p: GridPos| maze.get(p) == Some('.');
+/// let graph = compact_graph(
+///     &maze,
+///     |from, to| open(from) && open(to),
+///     |p, _| p == pos!(1, 0) || p == pos!(5, 4),
+/// );
+/// assert_eq!(graph.nodes, vec![pos!(1, 0), pos!(1, 1), pos!(5, 3), pos!(5, 4)]);
+///
+/// // Two routes around the loop, both six steps long
+/// let lengths: Vec<_> = graph.edges_from(pos!(1, 1)).filter(|c| c.to == pos!(5, 3)).map(|c| c.length).collect();
+/// assert_eq!(lengths, vec![6, 6]);
+/// ```
+pub fn compact_graph<T, P, N>(grid: &Grid<T>, passable: P, is_node: N) -> CorridorGraph
+where
+    P: Fn(GridPos, GridPos) -> bool,
+    N: Fn(GridPos, &T) -> bool,
+{
+    let passable = &passable;
+    let steps_from = move |pos: GridPos| {
+        pos.neighbours()
+            .filter(move |next| next.in_grid(grid) && passable(pos, *next))
+    };
+    let connected = |pos: GridPos| {
+        pos.neighbours()
+            .filter(|next| next.in_grid(grid) && (passable(pos, *next) || passable(*next, pos)))
+            .count()
+    };
+
+    let nodes: Vec<_> = grid
+        .positions()
+        .filter(|&p| is_node(p, &grid.grid[p.1 as usize][p.0 as usize]) || connected(p) >= 3)
+        .collect();
+    let is_node: HashSet<_> = nodes.iter().copied().collect();
+
+    let edges = nodes
+        .iter()
+        .map(|&node| {
+            let corridors = steps_from(node)
+                .filter_map(|first| {
+                    // Walk along the corridor until we reach another node
+                    let (mut previous, mut current) = (node, first);
+                    let mut cells = Vec::new();
+                    while !is_node.contains(&current) {
+                        cells.push(current);
+                        let next = steps_from(current).find(|&next| next != previous)?;
+                        (previous, current) = (current, next);
+                    }
+
+                    Some(Corridor {
+                        from: node,
+                        to: current,
+                        length: cells.len() + 1,
+                        cells,
+                    })
+                })
+                .collect();
+            (node, corridors)
+        })
+        .collect();
+
+    CorridorGraph { nodes, edges }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::pos;
+
+    #[test]
+    fn one_way_tiles() {
+        // A loop where the right hand side can only be walked downwards
+        let maze = Grid::new(vec![
+            "S....".chars().collect(),
+            ".###v".chars().collect(),
+            "....E".chars().collect(),
+        ]);
+        let passable = |from: GridPos, to: GridPos| match (maze.get(from), maze.get(to)) {
+            (Some('#'), _) | (_, Some('#')) => false,
+            (Some('v'), _) | (_, Some('v')) => to.1 > from.1,
+            _ => true,
+        };
+        let graph = compact_graph(&maze, passable, |_, &c| c == 'S' || c == 'E');
+        assert_eq!(graph.nodes, vec![pos!(0, 0), pos!(4, 2)]);
+
+        let from_start: Vec<_> = graph.edges_from(pos!(0, 0)).map(|c| c.length).collect();
+        assert_eq!(from_start, vec![6, 6]);
+
+        // Can only walk back along the bottom
+        let from_end: Vec<_> = graph.edges_from(pos!(4, 2)).collect();
+        assert_eq!(from_end.len(), 1);
+        assert_eq!(from_end[0].to, pos!(0, 0));
+        assert_eq!(from_end[0].cells.first(), Some(&pos!(3, 2)));
+    }
+}
@@ -0,0 +1,198 @@
+use std::{cmp::Reverse, collections::BinaryHeap};
+
+use smallvec::SmallVec;
+
+use super::{Grid, GridPos};
+use crate::pos;
+
+/// Which neighbours of a cell can be moved to in a single step
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Connectivity {
+    /// Only the four cartesian neighbours
+    Four,
+
+    /// The cartesian neighbours and the four diagonals
+    Eight,
+}
+
+impl Connectivity {
+    fn neighbours(self, pos: GridPos) -> SmallVec<[GridPos; 8]> {
+        match self {
+            Connectivity::Four => pos.neighbours().collect(),
+            Connectivity::Eight => pos.all_neighbours().collect(),
+        }
+    }
+}
+
+/// The closest source to a cell in a distance field
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Nearest {
+    pub source: GridPos,
+    pub distance: usize,
+}
+
+impl<T> Grid<T> {
+    /// Find the number of steps from the nearest source to every cell, only moving
+    /// horizontally and vertically through passable cells
+    /// Cells that can't be reached from any source are None
+    ///
+    /// # Examples
+    /// ```
+    /// use aoc::{pos, grid::{Grid, GridPos}};
+    /// let grid = Grid::new(vec![
+    ///     "..#.".chars().collect(),
+    ///     "..#.".chars().collect(),
+    ///     "....".chars().collect(),
+    /// ]);
+    /// let distances = grid.distance_field([pos!(0, 0)], |_, &c| c == '.');
+    /// assert_eq!(distances.get((3, 0)), Some(Some(7)));
+    /// assert_eq!(distances.get((2, 0)), Some(None));
+    /// ```
+    pub fn distance_field<S, F>(&self, sources: S, passable: F) -> Grid<Option<usize>>
+    where
+        S: IntoIterator<Item = GridPos>,
+        F: Fn(GridPos, &T) -> bool,
+    {
+        self.weighted_distance_field(sources, Connectivity::Four, |p, cell| {
+            passable(p, cell).then_some(1)
+        })
+    }
+
+    /// Find the number of steps from the nearest source to every cell, moving in any of the
+    /// eight directions through passable cells
+    /// Cells that can't be reached from any source are None
+    ///
+    /// # Examples
+    /// ```
+    /// use aoc::{pos, grid::{Grid, GridPos}};
+    /// let grid: Grid<()> = Grid::from_default(5, 5);
+    /// let distances = grid.distance_field_8([pos!(0, 0)], |_, _| true);
+    /// assert_eq!(distances.get((4, 4)), Some(Some(4)));
+    /// assert_eq!(distances.get((4, 1)), Some(Some(4)));
+    /// ```
+    pub fn distance_field_8<S, F>(&self, sources: S, passable: F) -> Grid<Option<usize>>
+    where
+        S: IntoIterator<Item = GridPos>,
+        F: Fn(GridPos, &T) -> bool,
+    {
+        self.weighted_distance_field(sources, Connectivity::Eight, |p, cell| {
+            passable(p, cell).then_some(1)
+        })
+    }
+
+    /// Find the cheapest total cost of moving from the nearest source to every cell
+    /// `cost` gives the cost of moving into a cell, or None if the cell is impassable.
+    /// Sources cost nothing to start on, and the cost of a cell is paid on entering it, so this
+    /// is the cost of paths leaving the sources, which differs from the cost of the same paths
+    /// walked back towards the sources when costs vary.
+    ///
+    /// # Examples
+    /// ```
+    /// use aoc::{pos, grid::{Connectivity, Grid, GridPos}};
+    /// let grid = Grid::new(vec![vec![1, 9, 1], vec![1, 1, 1]]);
+    /// let costs = grid.weighted_distance_field([pos!(0, 0)], Connectivity::Four, |_, &c| Some(c));
+    /// assert_eq!(costs.get((2, 0)), Some(Some(4)));
+    ///
+    /// // Entering the far cell costs 5, but leaving it to reach the source would only cost 1
+    /// let grid = Grid::new(vec![vec![1, 5]]);
+    /// let costs = grid.weighted_distance_field([pos!(0, 0)], Connectivity::Four, |_, &c| Some(c));
+    /// assert_eq!(costs.get((1, 0)), Some(Some(5)));
+    /// ```
+    pub fn weighted_distance_field<S, F>(
+        &self,
+        sources: S,
+        connectivity: Connectivity,
+        cost: F,
+    ) -> Grid<Option<usize>>
+    where
+        S: IntoIterator<Item = GridPos>,
+        F: Fn(GridPos, &T) -> Option<usize>,
+    {
+        let nearest = self.nearest_sources(sources, connectivity, cost);
+        Grid::from_fn(self.width, self.height, |p| {
+            nearest.get(p).flatten().map(|n| n.distance)
+        })
+    }
+
+    /// Find the nearest source to every cell, and the cheapest total cost of moving from that
+    /// source to the cell
+    /// `cost` gives the cost of moving into a cell, or None if the cell is impassable.
+    /// When several sources are equally close, the one listed first wins.
+    ///
+    /// # Examples
+    /// ```
+    /// use aoc::{pos, grid::{Connectivity, Grid, GridPos, Nearest}};
+    /// let grid: Grid<()> = Grid::from_default(5, 1);
+    /// let nearest = grid.nearest_sources([pos!(0, 0), pos!(4, 0)], Connectivity::Four, |_, _| Some(1));
+    /// assert_eq!(nearest.get((1, 0)), Some(Some(Nearest { source: pos!(0, 0), distance: 1 })));
+    /// assert_eq!(nearest.get((2, 0)), Some(Some(Nearest { source: pos!(0, 0), distance: 2 })));
+    /// assert_eq!(nearest.get((3, 0)), Some(Some(Nearest { source: pos!(4, 0), distance: 1 })));
+    /// ```
+    pub fn nearest_sources<S, F>(
+        &self,
+        sources: S,
+        connectivity: Connectivity,
+        cost: F,
+    ) -> Grid<Option<Nearest>>
+    where
+        S: IntoIterator<Item = GridPos>,
+        F: Fn(GridPos, &T) -> Option<usize>,
+    {
+        let mut field: Grid<Option<Nearest>> = Grid::from_fn(self.width, self.height, |_| None);
+        let sources: Vec<_> = sources.into_iter().filter(|p| p.in_grid(self)).collect();
+
+        // Dijkstra's from every source at once, ties broken by source order
+        let mut frontier = BinaryHeap::new();
+        for (i, &source) in sources.iter().enumerate() {
+            frontier.push(Reverse((0, i, source.0, source.1)));
+        }
+
+        while let Some(Reverse((distance, i, x, y))) = frontier.pop() {
+            let pos = pos!(x, y);
+            let cell = field.get_unchecked_mut(pos);
+            if cell.is_some() {
+                continue;
+            }
+            *cell = Some(Nearest {
+                source: sources[i],
+                distance,
+            });
+
+            for next in connectivity.neighbours(pos) {
+                if field.get(next) != Some(None) {
+                    continue;
+                }
+                if let Some(step) = cost(next, &self.grid[next.1 as usize][next.0 as usize]) {
+                    frontier.push(Reverse((distance + step, i, next.0, next.1)));
+                }
+            }
+        }
+
+        field
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use proptest::prelude::*;
+
+    proptest! {
+        #[test]
+        fn distance_is_manhattan_on_open_grid(
+            sources in prop::collection::vec((0..8isize, 0..6isize), 1..4),
+        ) {
+            let grid: Grid<()> = Grid::from_default(8, 6);
+            let sources: Vec<_> = sources.into_iter().map(|(x, y)| pos!(x, y)).collect();
+            let nearest = grid.nearest_sources(sources.clone(), Connectivity::Four, |_, _| Some(1));
+            for p in grid.positions() {
+                let manhattan = |s: &GridPos| s.manhattan(p);
+                let expected = sources.iter().map(manhattan).min().unwrap();
+                let found = nearest.get(p).flatten().unwrap();
+                prop_assert_eq!(found.distance, expected);
+                prop_assert_eq!(manhattan(&found.source), expected);
+                prop_assert_eq!(sources.iter().position(|s| manhattan(s) == expected), sources.iter().position(|&s| s == found.source));
+            }
+        }
+    }
+}
@@ -1,21 +1,346 @@
 use derive_more::{Add, AddAssign, From, Into, Mul, MulAssign, Sub, SubAssign};
+use std::{
+    fmt::{Debug, Display},
+    hash::Hash,
+    ops::{Add, AddAssign, Mul, MulAssign, Neg, Sub, SubAssign},
+    str::FromStr,
+};
 
-use crate::direction::Dir;
+use crate::direction::{Dir, Dir8};
 
 use super::Grid;
 
+/// A signed integer type that can be used for the coordinates of a [`GridPos`]
+pub trait Coord:
+    Copy
+    + Eq
+    + Ord
+    + Hash
+    + Default
+    + Debug
+    + Display
+    + Add<Output = Self>
+    + Sub<Output = Self>
+    + Mul<Output = Self>
+    + Neg<Output = Self>
+    + AddAssign
+    + SubAssign
+    + MulAssign
+    + FromStr
+{
+    /// The unsigned type of the same width, used for distances
+    type Unsigned: Copy
+        + Eq
+        + Ord
+        + Debug
+        + Display
+        + Add<Output = Self::Unsigned>
+        + Mul<Output = Self::Unsigned>;
+
+    const ZERO: Self;
+    const ONE: Self;
+    const NEG_ONE: Self;
+
+    fn abs(self) -> Self;
+    fn signum(self) -> Self;
+    fn abs_diff(self, other: Self) -> Self::Unsigned;
+    fn checked_add(self, other: Self) -> Option<Self>;
+    fn checked_sub(self, other: Self) -> Option<Self>;
+    fn checked_mul(self, other: Self) -> Option<Self>;
+
+    /// Widen to an `i128` for intermediate calculations that might overflow
+    fn as_i128(self) -> i128;
+}
+
+macro_rules! impl_coord {
+    ($($signed: ty => $unsigned: ty),*) => {
+        $(
+            impl Coord for $signed {
+                type Unsigned = $unsigned;
+
+                const ZERO: Self = 0;
+                const ONE: Self = 1;
+                const NEG_ONE: Self = -1;
+
+                fn abs(self) -> Self {
+                    <$signed>::abs(self)
+                }
+
+                fn signum(self) -> Self {
+                    <$signed>::signum(self)
+                }
+
+                fn abs_diff(self, other: Self) -> Self::Unsigned {
+                    <$signed>::abs_diff(self, other)
+                }
+
+                fn checked_add(self, other: Self) -> Option<Self> {
+                    <$signed>::checked_add(self, other)
+                }
+
+                fn checked_sub(self, other: Self) -> Option<Self> {
+                    <$signed>::checked_sub(self, other)
+                }
+
+                fn checked_mul(self, other: Self) -> Option<Self> {
+                    <$signed>::checked_mul(self, other)
+                }
+
+                fn as_i128(self) -> i128 {
+                    self as i128
+                }
+            }
+
+            /// Scale an offset, e.g `3 * GridPos::NORTH`
+            /// (`GridPos::NORTH * 3` also works)
+            impl Mul<GridPos<$signed>> for $signed {
+                type Output = GridPos<$signed>;
+
+                fn mul(self, rhs: GridPos<$signed>) -> Self::Output {
+                    GridPos(self * rhs.0, self * rhs.1)
+                }
+            }
+        )*
+    };
+}
+
+impl_coord!(i8 => u8, i16 => u16, i32 => u32, i64 => u64, i128 => u128, isize => usize);
+
 /// A signed position or offset into a grid
+/// Coordinates are `isize` unless another integer type is specified
 #[derive(
     Clone, Copy, PartialEq, Eq, Add, AddAssign, Sub, SubAssign, Mul, MulAssign, Into, From, Hash,
 )]
-pub struct GridPos(pub isize, pub isize);
+pub struct GridPos<T = isize>(pub T, pub T);
 
 /// Helper for constructing a position
+/// An integer type can be given after a semicolon, otherwise `isize` is used
+///
+/// # Examples
+/// ```
+/// use aoc::{pos, grid::GridPos};
+/// let a: GridPos = pos!(1, 2);
+/// let b: GridPos<i128> = pos!(1, 2; i128);
+/// assert_eq!(a.0 as i128, b.0);
+/// ```
 #[macro_export]
 macro_rules! pos {
     ($x: expr, $y: expr) => {
         GridPos($x as isize, $y as isize)
     };
+    ($x: expr, $y: expr; $t: ty) => {
+        GridPos($x as $t, $y as $t)
+    };
+}
+
+impl<T: Coord> GridPos<T> {
+    /// The origin, or an offset of zero
+    pub const ZERO: Self = Self(T::ZERO, T::ZERO);
+
+    /// A single step north (up)
+    pub const NORTH: Self = Self(T::ZERO, T::NEG_ONE);
+
+    /// A single step east (right)
+    pub const EAST: Self = Self(T::ONE, T::ZERO);
+
+    /// A single step south (down)
+    pub const SOUTH: Self = Self(T::ZERO, T::ONE);
+
+    /// A single step west (left)
+    pub const WEST: Self = Self(T::NEG_ONE, T::ZERO);
+
+    /// Manhattan (taxicab) distance to another position
+    ///
+    /// # Examples
+    /// ```
+    /// use aoc::{pos, grid::GridPos};
+    /// assert_eq!(pos!(1, 6).manhattan(pos!(5, 11)), 9);
+    /// ```
+    pub fn manhattan(&self, other: Self) -> T::Unsigned {
+        self.0.abs_diff(other.0) + self.1.abs_diff(other.1)
+    }
+
+    /// Chebyshev (chessboard) distance to another position
+    ///
+    /// # Examples
+    /// ```
+    /// use aoc::{pos, grid::GridPos};
+    /// assert_eq!(pos!(1, 6).chebyshev(pos!(5, 11)), 5);
+    /// ```
+    pub fn chebyshev(&self, other: Self) -> T::Unsigned {
+        self.0.abs_diff(other.0).max(self.1.abs_diff(other.1))
+    }
+
+    /// Squared euclidean distance to another position
+    ///
+    /// # Examples
+    /// ```
+    /// use aoc::{pos, grid::GridPos};
+    /// assert_eq!(pos!(1, 6).distance_squared(pos!(4, 2)), 25);
+    /// ```
+    pub fn distance_squared(&self, other: Self) -> T::Unsigned {
+        let (dx, dy) = (self.0.abs_diff(other.0), self.1.abs_diff(other.1));
+        dx * dx + dy * dy
+    }
+
+    /// Take the absolute value of each component
+    pub fn abs(&self) -> Self {
+        Self(self.0.abs(), self.1.abs())
+    }
+
+    /// Take the sign (-1, 0 or 1) of each component
+    ///
+    /// # Examples
+    /// ```
+    /// use aoc::{pos, grid::GridPos};
+    /// assert_eq!(pos!(-7, 0).signum(), GridPos::WEST);
+    /// ```
+    pub fn signum(&self) -> Self {
+        Self(self.0.signum(), self.1.signum())
+    }
+
+    /// Add two positions, returning None on overflow
+    ///
+    /// # Examples
+    /// ```
+    /// use aoc::{pos, grid::GridPos};
+    /// assert_eq!(pos!(i64::MAX, 0; i64).checked_add(GridPos::EAST), None);
+    /// assert_eq!(pos!(1, 0; i64).checked_add(GridPos::EAST), Some(pos!(2, 0; i64)));
+    /// ```
+    pub fn checked_add(&self, other: Self) -> Option<Self> {
+        Some(Self(
+            self.0.checked_add(other.0)?,
+            self.1.checked_add(other.1)?,
+        ))
+    }
+
+    /// Subtract two positions, returning None on overflow
+    pub fn checked_sub(&self, other: Self) -> Option<Self> {
+        Some(Self(
+            self.0.checked_sub(other.0)?,
+            self.1.checked_sub(other.1)?,
+        ))
+    }
+
+    /// Scale an offset, returning None on overflow
+    pub fn checked_mul(&self, scale: T) -> Option<Self> {
+        Some(Self(self.0.checked_mul(scale)?, self.1.checked_mul(scale)?))
+    }
+
+    /// Convert to another coordinate type, returning None if either coordinate doesn't fit
+    ///
+    /// # Examples
+    /// ```
+    /// use aoc::{pos, grid::GridPos};
+    /// let wide: GridPos<i128> = pos!(3, -4).try_cast().unwrap();
+    /// assert_eq!(wide, pos!(3, -4; i128));
+    /// assert_eq!(pos!(300, 0; i64).try_cast::<i8>(), None);
+    /// ```
+    pub fn try_cast<U: Coord + TryFrom<T>>(&self) -> Option<GridPos<U>> {
+        Some(GridPos(
+            U::try_from(self.0).ok()?,
+            U::try_from(self.1).ok()?,
+        ))
+    }
+
+    /// Move some number of steps in a direction
+    ///
+    /// # Examples
+    /// ```
+    /// use aoc::{pos, direction::Dir, grid::GridPos};
+    /// assert_eq!(pos!(2, 2).step(Dir::North, 3), pos!(2, -1));
+    /// ```
+    pub fn step(&self, dir: Dir, n: T) -> Self {
+        *self + GridPos::from(dir) * n
+    }
+
+    /// Get cartesian neighbours of this position along with the direction to each
+    /// (not guaranteed to be in bounds)
+    ///
+    /// # Examples
+    /// ```
+    /// use aoc::{pos, direction::Dir, grid::GridPos};
+    /// assert!(pos!(0, 0).neighbours_with_dir().any(|n| n == (Dir::West, pos!(-1, 0))));
+    /// ```
+    pub fn neighbours_with_dir(&self) -> impl Iterator<Item = (Dir, Self)> {
+        let pos = *self;
+        [Dir::West, Dir::East, Dir::North, Dir::South]
+            .into_iter()
+            .map(move |dir| (dir, pos.step(dir, T::ONE)))
+    }
+
+    /// Get cartesian and diagonal neighbours of this position along with the direction to
+    /// each, clockwise from north
+    /// (not guaranteed to be in bounds)
+    pub fn all_neighbours_with_dir(&self) -> impl Iterator<Item = (Dir8, Self)> {
+        let pos = *self;
+        Dir8::ALL
+            .into_iter()
+            .map(move |dir| (dir, pos + GridPos::from(dir)))
+    }
+
+    /// Rotate a quarter turn clockwise around a pivot
+    /// Rotating a direction's offset around the origin matches [`Dir::turn_right`]
+    ///
+    /// # Examples
+    /// ```
+    /// use aoc::{pos, grid::GridPos};
+    /// assert_eq!(GridPos::<isize>::NORTH.rotate_cw(GridPos::ZERO), GridPos::EAST);
+    /// assert_eq!(pos!(3, 1).rotate_cw(pos!(1, 1)), pos!(1, 3));
+    /// ```
+    pub fn rotate_cw(&self, about: Self) -> Self {
+        let offset = *self - about;
+        about + Self(-offset.1, offset.0)
+    }
+
+    /// Rotate a quarter turn anticlockwise around a pivot
+    /// Rotating a direction's offset around the origin matches [`Dir::turn_left`]
+    pub fn rotate_ccw(&self, about: Self) -> Self {
+        let offset = *self - about;
+        about + Self(offset.1, -offset.0)
+    }
+
+    /// Mirror left to right across the vertical line through a pivot
+    ///
+    /// # Examples
+    /// ```
+    /// use aoc::{pos, grid::GridPos};
+    /// assert_eq!(pos!(3, 5).reflect_x(pos!(1, 0)), pos!(-1, 5));
+    /// ```
+    pub fn reflect_x(&self, about: Self) -> Self {
+        Self(about.0 + about.0 - self.0, self.1)
+    }
+
+    /// Mirror top to bottom across the horizontal line through a pivot
+    pub fn reflect_y(&self, about: Self) -> Self {
+        Self(self.0, about.1 + about.1 - self.1)
+    }
+
+    /// Mirror across the `\` diagonal through a pivot
+    /// For a direction's offset this is how a beam bounces off a `\` mirror
+    ///
+    /// # Examples
+    /// ```
+    /// use aoc::grid::GridPos;
+    /// assert_eq!(GridPos::<isize>::EAST.reflect_diagonal(GridPos::ZERO), GridPos::SOUTH);
+    /// ```
+    pub fn reflect_diagonal(&self, about: Self) -> Self {
+        let offset = *self - about;
+        about + Self(offset.1, offset.0)
+    }
+
+    /// Mirror across the `/` diagonal through a pivot
+    /// For a direction's offset this is how a beam bounces off a `/` mirror
+    ///
+    /// # Examples
+    /// ```
+    /// use aoc::grid::GridPos;
+    /// assert_eq!(GridPos::<isize>::EAST.reflect_antidiagonal(GridPos::ZERO), GridPos::NORTH);
+    /// ```
+    pub fn reflect_antidiagonal(&self, about: Self) -> Self {
+        let offset = *self - about;
+        about + Self(-offset.1, -offset.0)
+    }
 }
 
 impl GridPos {
@@ -28,42 +353,326 @@ impl GridPos {
             .map(|(x, y)| Self(x, y))
     }
 
+    /// Get cartesian and diagonal neighbours of this position
+    /// (not guaranteed to be in bounds)
+    pub fn all_neighbours(&self) -> impl Iterator<Item = Self> {
+        let (x, y) = (self.0, self.1);
+        (-1..=1)
+            .flat_map(|dy| (-1..=1).map(move |dx| (dx, dy)))
+            .filter(|&d| d != (0, 0))
+            .map(move |(dx, dy)| Self(x + dx, y + dy))
+    }
+
     /// Whether this position is in bounds for a given grid
     pub fn in_grid<T>(&self, grid: &Grid<T>) -> bool {
         (0..grid.width as isize).contains(&self.0) && (0..grid.height as isize).contains(&self.1)
     }
 }
 
-impl std::fmt::Debug for GridPos {
+impl<T: Coord> Neg for GridPos<T> {
+    type Output = Self;
+
+    fn neg(self) -> Self::Output {
+        Self(-self.0, -self.1)
+    }
+}
+
+impl<T: Coord> std::fmt::Debug for GridPos<T> {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         write!(f, "Pos({}, {})", self.0, self.1)
     }
 }
 
-impl From<Dir> for GridPos {
+/// Formats as `(x, y)`
+impl<T: Coord> Display for GridPos<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "({}, {})", self.0, self.1)
+    }
+}
+
+/// Parses either `x,y` or `(x, y)`
+///
+/// # Examples
+/// ```
+/// use aoc::{pos, grid::GridPos};
+/// assert_eq!("3,4".parse(), Ok(pos!(3, 4)));
+/// assert_eq!("(-3, 4)".parse(), Ok(pos!(-3, 4)));
+/// assert_eq!(pos!(5, -6).to_string().parse(), Ok(pos!(5, -6)));
+/// assert!("3;4".parse::<GridPos>().is_err());
+/// ```
+impl<T: Coord> FromStr for GridPos<T> {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let trimmed = s.trim();
+        let inner = trimmed
+            .strip_prefix('(')
+            .and_then(|rest| rest.strip_suffix(')'))
+            .unwrap_or(trimmed);
+        let (x, y) = inner
+            .split_once(',')
+            .ok_or_else(|| format!("Expected a position like 3,4 but found {s:?}"))?;
+        let coord = |c: &str| {
+            c.trim()
+                .parse()
+                .map_err(|_| format!("Invalid coordinate {c:?} in position {s:?}"))
+        };
+        Ok(Self(coord(x)?, coord(y)?))
+    }
+}
+
+impl<T: Coord> From<Dir> for GridPos<T> {
     fn from(value: Dir) -> Self {
         match value {
-            Dir::North => pos!(0, -1),
-            Dir::South => pos!(0, 1),
-            Dir::West => pos!(-1, 0),
-            Dir::East => pos!(1, 0),
+            Dir::North => Self::NORTH,
+            Dir::South => Self::SOUTH,
+            Dir::West => Self::WEST,
+            Dir::East => Self::EAST,
         }
     }
 }
 
-impl TryFrom<GridPos> for Dir {
+impl<T: Coord> TryFrom<GridPos<T>> for Dir {
     type Error = String;
 
-    fn try_from(value: GridPos) -> Result<Self, Self::Error> {
+    fn try_from(value: GridPos<T>) -> Result<Self, Self::Error> {
         use Dir::*;
 
-        let (x_sign, y_sign) = (value.0.signum(), value.1.signum());
-        match (x_sign, y_sign) {
-            (1, 0) => Ok(East),
-            (-1, 0) => Ok(West),
-            (0, 1) => Ok(South),
-            (0, -1) => Ok(North),
+        match value.signum() {
+            p if p == GridPos::EAST => Ok(East),
+            p if p == GridPos::WEST => Ok(West),
+            p if p == GridPos::SOUTH => Ok(South),
+            p if p == GridPos::NORTH => Ok(North),
             p => Err(format!("Dir cannot represent position {p:?}")),
         }
     }
 }
+
+impl<T: Coord> From<Dir8> for GridPos<T> {
+    fn from(value: Dir8) -> Self {
+        match value {
+            Dir8::North => Self::NORTH,
+            Dir8::NorthEast => Self::NORTH + Self::EAST,
+            Dir8::East => Self::EAST,
+            Dir8::SouthEast => Self::SOUTH + Self::EAST,
+            Dir8::South => Self::SOUTH,
+            Dir8::SouthWest => Self::SOUTH + Self::WEST,
+            Dir8::West => Self::WEST,
+            Dir8::NorthWest => Self::NORTH + Self::WEST,
+        }
+    }
+}
+
+/// Finds the direction of an offset along a row, column or 45° diagonal
+///
+/// # Examples
+/// ```
+/// use aoc::{pos, direction::Dir8, grid::GridPos};
+/// assert_eq!(Dir8::try_from(pos!(-3, 3)), Ok(Dir8::SouthWest));
+/// assert!(Dir8::try_from(pos!(-3, 2)).is_err());
+/// ```
+impl<T: Coord> TryFrom<GridPos<T>> for Dir8 {
+    type Error = String;
+
+    fn try_from(value: GridPos<T>) -> Result<Self, Self::Error> {
+        let aligned = value.0 == T::ZERO || value.1 == T::ZERO || value.0.abs() == value.1.abs();
+        Dir8::ALL
+            .into_iter()
+            .find(|&dir| aligned && GridPos::from(dir) == value.signum())
+            .ok_or_else(|| format!("Dir8 cannot represent position {value:?}"))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use proptest::prelude::*;
+
+    fn any_pos() -> impl Strategy<Value = GridPos> {
+        (-1000..1000isize, -1000..1000isize).prop_map(|(x, y)| GridPos(x, y))
+    }
+
+    #[test]
+    fn unit_constants_match_dirs() {
+        assert_eq!(GridPos::<isize>::from(Dir::North), GridPos::NORTH);
+        assert_eq!(GridPos::<isize>::from(Dir::East), GridPos::EAST);
+        assert_eq!(GridPos::<isize>::from(Dir::South), GridPos::SOUTH);
+        assert_eq!(GridPos::<isize>::from(Dir::West), GridPos::WEST);
+        assert_eq!(GridPos::<isize>::NORTH + GridPos::SOUTH, GridPos::ZERO);
+        assert_eq!(GridPos::<isize>::EAST + GridPos::WEST, GridPos::ZERO);
+    }
+
+    #[test]
+    fn dir_conversions_for_every_width() {
+        macro_rules! check {
+            ($($t: ty),*) => {
+                $(
+                    for dir in [Dir::North, Dir::East, Dir::South, Dir::West] {
+                        let offset: GridPos<$t> = dir.into();
+                        assert_eq!(Dir::try_from(offset), Ok(dir));
+                        assert_eq!(Dir::try_from(offset * 5), Ok(dir));
+                    }
+                    assert!(Dir::try_from(pos!(1, 1; $t)).is_err());
+                    assert!(Dir::try_from(GridPos::<$t>::ZERO).is_err());
+                )*
+            };
+        }
+        check!(i8, i16, i32, i64, i128, isize);
+    }
+
+    #[test]
+    fn neighbours_with_dir_match_neighbours() {
+        let p = pos!(4, -2);
+        let tagged: Vec<_> = p.neighbours_with_dir().collect();
+        assert_eq!(
+            tagged.iter().map(|n| n.1).collect::<Vec<_>>(),
+            p.neighbours().collect::<Vec<_>>()
+        );
+        for (dir, n) in tagged {
+            assert_eq!(Dir::try_from(n - p), Ok(dir));
+            assert_eq!(p.step(dir, 1), n);
+        }
+    }
+
+    #[test]
+    fn dir8_conversions() {
+        for dir in Dir8::ALL {
+            let offset: GridPos = dir.into();
+            assert_eq!(Dir8::try_from(offset), Ok(dir));
+            assert_eq!(Dir8::try_from(offset * 7), Ok(dir));
+            assert_eq!(offset.chebyshev(GridPos::ZERO), 1);
+            assert_eq!(GridPos::from(dir.opposite()), -offset);
+            assert_eq!(
+                GridPos::from(dir.turn_right()),
+                offset.rotate_cw(GridPos::ZERO)
+            );
+            assert_eq!(
+                GridPos::from(dir.turn_left()),
+                offset.rotate_ccw(GridPos::ZERO)
+            );
+            assert_eq!(dir.turn_right_45().turn_left_45(), dir);
+            assert_eq!(dir.turn_right_45().turn_right_45(), dir.turn_right());
+            assert_eq!(dir.is_diagonal(), offset.0 != 0 && offset.1 != 0);
+
+            if let Ok(cardinal) = Dir::try_from(dir) {
+                assert_eq!(Dir8::from(cardinal), dir);
+                assert_eq!(GridPos::from(cardinal), offset);
+            }
+        }
+        assert!(Dir8::try_from(GridPos::<isize>::ZERO).is_err());
+
+        let p = pos!(3, 3);
+        let mut tagged: Vec<_> = p.all_neighbours_with_dir().map(|(_, n)| n).collect();
+        let mut untagged: Vec<_> = p.all_neighbours().collect();
+        tagged.sort_by_key(|n| (n.1, n.0));
+        untagged.sort_by_key(|n| (n.1, n.0));
+        assert_eq!(tagged, untagged);
+    }
+
+    #[test]
+    fn rotations_match_dir_turns() {
+        for dir in [Dir::North, Dir::East, Dir::South, Dir::West] {
+            let offset: GridPos = dir.into();
+            assert_eq!(offset.rotate_cw(GridPos::ZERO), dir.turn_right().into());
+            assert_eq!(offset.rotate_ccw(GridPos::ZERO), dir.turn_left().into());
+        }
+    }
+
+    #[test]
+    fn diagonal_reflections_match_mirrors() {
+        use Dir::*;
+
+        // (heading, heading after `/`, heading after `\`)
+        let bounces = [
+            (North, East, West),
+            (East, North, South),
+            (South, West, East),
+            (West, South, North),
+        ];
+        for (dir, slash, backslash) in bounces {
+            let offset: GridPos = dir.into();
+            assert_eq!(offset.reflect_antidiagonal(GridPos::ZERO), slash.into());
+            assert_eq!(offset.reflect_diagonal(GridPos::ZERO), backslash.into());
+        }
+    }
+
+    #[test]
+    fn checked_arithmetic() {
+        let max = pos!(i64::MAX, 0; i64);
+        assert_eq!(max.checked_add(GridPos::EAST), None);
+        assert_eq!(
+            max.checked_sub(GridPos::EAST),
+            Some(pos!(i64::MAX - 1, 0; i64))
+        );
+        assert_eq!(max.checked_mul(2), None);
+        assert_eq!(
+            max.try_cast::<i128>().unwrap().checked_mul(2),
+            Some(pos!(i64::MAX as i128 * 2, 0; i128))
+        );
+    }
+
+    #[test]
+    fn scalar_multiplication() {
+        let east: GridPos = GridPos::EAST;
+        assert_eq!(east * 4, pos!(4, 0));
+        assert_eq!(4 * east, pos!(4, 0));
+        assert_eq!(-2 * pos!(3, -1), pos!(-6, 2));
+        assert_eq!(-pos!(3, -1), pos!(-3, 1));
+    }
+
+    proptest! {
+        #[test]
+        fn distances_are_metrics(a in any_pos(), b in any_pos(), c in any_pos()) {
+            prop_assert_eq!(a.manhattan(a), 0);
+            prop_assert_eq!(a.manhattan(b), b.manhattan(a));
+            prop_assert!(a.manhattan(c) <= a.manhattan(b) + b.manhattan(c));
+            prop_assert_eq!(a.chebyshev(b), b.chebyshev(a));
+            prop_assert!(a.chebyshev(c) <= a.chebyshev(b) + b.chebyshev(c));
+            prop_assert!(a.chebyshev(b) <= a.manhattan(b));
+            prop_assert!(a.manhattan(b) <= 2 * a.chebyshev(b));
+            prop_assert_eq!(a.distance_squared(b), b.distance_squared(a));
+        }
+
+        #[test]
+        fn distances_agree_with_offsets(a in any_pos(), b in any_pos()) {
+            let d = (b - a).abs();
+            prop_assert_eq!(a.manhattan(b), (d.0 + d.1) as usize);
+            prop_assert_eq!(a.chebyshev(b), d.0.max(d.1) as usize);
+            prop_assert_eq!(a.distance_squared(b), (d.0 * d.0 + d.1 * d.1) as usize);
+        }
+
+        #[test]
+        fn abs_and_signum_recombine(a in any_pos()) {
+            let (abs, sign) = (a.abs(), a.signum());
+            prop_assert_eq!(pos!(abs.0 * sign.0, abs.1 * sign.1), a);
+            prop_assert!(abs.0 >= 0 && abs.1 >= 0);
+        }
+
+        #[test]
+        fn rotations_and_reflections_are_isometries(a in any_pos(), pivot in any_pos()) {
+            prop_assert_eq!(a.rotate_cw(pivot).rotate_ccw(pivot), a);
+            prop_assert_eq!(a.rotate_cw(pivot).rotate_cw(pivot), a.reflect_x(pivot).reflect_y(pivot));
+            for transformed in [
+                a.rotate_cw(pivot),
+                a.rotate_ccw(pivot),
+                a.reflect_x(pivot),
+                a.reflect_y(pivot),
+                a.reflect_diagonal(pivot),
+                a.reflect_antidiagonal(pivot),
+            ] {
+                prop_assert_eq!(transformed.distance_squared(pivot), a.distance_squared(pivot));
+            }
+            prop_assert_eq!(a.reflect_x(pivot).reflect_x(pivot), a);
+            prop_assert_eq!(a.reflect_y(pivot).reflect_y(pivot), a);
+            prop_assert_eq!(a.reflect_diagonal(pivot).reflect_diagonal(pivot), a);
+            prop_assert_eq!(a.reflect_antidiagonal(pivot).reflect_antidiagonal(pivot), a);
+        }
+
+        #[test]
+        fn scaling_is_repeated_addition(a in any_pos(), n in 0..20isize) {
+            let repeated = (0..n).fold(GridPos::ZERO, |total, _| total + a);
+            prop_assert_eq!(n * a, repeated);
+            prop_assert_eq!(a * n, repeated);
+        }
+    }
+}
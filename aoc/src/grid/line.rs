@@ -0,0 +1,192 @@
+use super::GridPos;
+use crate::{direction::Dir, pos};
+
+/// An iterator over the positions on a straight line between two grid positions
+///
+/// Horizontal, vertical and 45° lines visit exactly the positions on the line, and any other
+/// line is approximated using Bresenham's algorithm.
+#[derive(Debug, Clone)]
+pub struct Line {
+    current: GridPos,
+    end: GridPos,
+    delta: GridPos,
+    step: GridPos,
+    error: isize,
+    include_end: bool,
+    done: bool,
+}
+
+impl Line {
+    fn new(from: GridPos, to: GridPos, include_end: bool) -> Self {
+        let delta = pos!((to.0 - from.0).abs(), -(to.1 - from.1).abs());
+        Self {
+            current: from,
+            end: to,
+            delta,
+            step: (to - from).signum(),
+            error: delta.0 + delta.1,
+            include_end,
+            done: !include_end && from == to,
+        }
+    }
+}
+
+impl Iterator for Line {
+    type Item = GridPos;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.done {
+            return None;
+        }
+
+        let pos = self.current;
+        if pos == self.end {
+            self.done = true;
+            return self.include_end.then_some(pos);
+        }
+
+        // Step along whichever axes keep us closest to the true line
+        let doubled = 2 * self.error;
+        if doubled >= self.delta.1 {
+            self.error += self.delta.1;
+            self.current.0 += self.step.0;
+        }
+        if doubled <= self.delta.0 {
+            self.error += self.delta.0;
+            self.current.1 += self.step.1;
+        }
+
+        if !self.include_end && self.current == self.end {
+            self.done = true;
+        }
+
+        Some(pos)
+    }
+}
+
+impl GridPos {
+    /// Iterate over the positions on a straight line to another position, including both ends
+    ///
+    /// # Examples
+    /// ```
+    /// use aoc::{pos, grid::GridPos};
+    /// let line: Vec<_> = pos!(0, 0).line_to(pos!(3, 0)).collect();
+    /// assert_eq!(line, vec![pos!(0, 0), pos!(1, 0), pos!(2, 0), pos!(3, 0)]);
+    ///
+    /// let line: Vec<_> = pos!(0, 0).line_to(pos!(6, 2)).collect();
+    /// assert_eq!(line[..4], [pos!(0, 0), pos!(1, 0), pos!(2, 1), pos!(3, 1)]);
+    /// assert_eq!(line[4..], [pos!(4, 1), pos!(5, 2), pos!(6, 2)]);
+    /// ```
+    pub fn line_to(&self, other: Self) -> Line {
+        Line::new(*self, other, true)
+    }
+
+    /// Iterate over the positions on a straight line to another position, stopping just before
+    /// reaching it
+    ///
+    /// # Examples
+    /// ```
+    /// use aoc::{pos, grid::GridPos};
+    /// let line: Vec<_> = pos!(2, 2).line_until(pos!(0, 0)).collect();
+    /// assert_eq!(line, vec![pos!(2, 2), pos!(1, 1)]);
+    /// ```
+    pub fn line_until(&self, other: Self) -> Line {
+        Line::new(*self, other, false)
+    }
+
+    /// Iterate over the positions visited when walking to another position one step at a time,
+    /// first horizontally and then vertically, including both ends
+    ///
+    /// # Examples
+    /// ```
+    /// use aoc::{pos, grid::GridPos};
+    /// let path: Vec<_> = pos!(0, 0).manhattan_path(pos!(2, -1)).collect();
+    /// assert_eq!(path, vec![pos!(0, 0), pos!(1, 0), pos!(2, 0), pos!(2, -1)]);
+    /// ```
+    pub fn manhattan_path(&self, other: Self) -> impl Iterator<Item = GridPos> {
+        let corner = pos!(other.0, self.1);
+        self.line_until(corner).chain(corner.line_to(other))
+    }
+}
+
+/// Follow a list of steps from the origin, giving the start and end position of each straight
+/// segment
+///
+/// The start of each segment gives the corners of the path, which is what
+/// [`crate::geometry`] expects when measuring the loop made by a dig plan.
+///
+/// # Examples
+/// ```
+/// use aoc::{pos, direction::Dir, grid::{segments_from_steps, GridPos}};
+/// let segments = segments_from_steps(&[(Dir::East, 3), (Dir::South, 2), (Dir::West, 3), (Dir::North, 2)]);
+/// assert_eq!(segments[1], (pos!(3, 0), pos!(3, 2)));
+/// assert_eq!(segments[3].1, pos!(0, 0));
+/// ```
+pub fn segments_from_steps(steps: &[(Dir, usize)]) -> Vec<(GridPos, GridPos)> {
+    steps
+        .iter()
+        .scan(pos!(0, 0), |pos, &(dir, len)| {
+            let start = *pos;
+            *pos += GridPos::from(dir) * len as isize;
+            Some((start, *pos))
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use proptest::prelude::*;
+
+    proptest! {
+        #[test]
+        fn lines_are_connected(x1 in -20..20isize, y1 in -20..20isize, x2 in -20..20isize, y2 in -20..20isize) {
+            let (from, to) = (pos!(x1, y1), pos!(x2, y2));
+            let line: Vec<_> = from.line_to(to).collect();
+            prop_assert_eq!(line.len(), from.chebyshev(to) + 1);
+            prop_assert_eq!(line.first(), Some(&from));
+            prop_assert_eq!(line.last(), Some(&to));
+            prop_assert!(line.windows(2).all(|w| w[0].chebyshev(w[1]) == 1));
+
+            let until: Vec<_> = from.line_until(to).collect();
+            prop_assert_eq!(&until[..], &line[..line.len() - 1]);
+        }
+
+        #[test]
+        fn manhattan_path_takes_single_steps(x1 in -20..20isize, y1 in -20..20isize, x2 in -20..20isize, y2 in -20..20isize) {
+            let (from, to) = (pos!(x1, y1), pos!(x2, y2));
+            let path: Vec<_> = from.manhattan_path(to).collect();
+            prop_assert_eq!(path.len(), from.manhattan(to) + 1);
+            prop_assert_eq!(path.last(), Some(&to));
+            prop_assert!(path.windows(2).all(|w| w[0].manhattan(w[1]) == 1));
+        }
+    }
+
+    #[test]
+    fn dig_plan_area() {
+        use crate::geometry;
+
+        // The dig plan from the 2023 day 18 sample
+        let steps = [
+            (Dir::East, 6),
+            (Dir::South, 5),
+            (Dir::West, 2),
+            (Dir::South, 2),
+            (Dir::East, 2),
+            (Dir::South, 2),
+            (Dir::West, 5),
+            (Dir::North, 2),
+            (Dir::West, 1),
+            (Dir::North, 2),
+            (Dir::East, 2),
+            (Dir::North, 3),
+            (Dir::West, 2),
+            (Dir::North, 2),
+        ];
+        let corners: Vec<_> = segments_from_steps(&steps)
+            .into_iter()
+            .map(|s| s.0)
+            .collect();
+        assert_eq!(geometry::enclosed_points(&corners), Some(62));
+    }
+}
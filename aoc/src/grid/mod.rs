@@ -10,14 +10,55 @@
 //! assert_eq!(grid.get((1, 1)), Some(3));
 //! ```
 
+mod compressed;
+mod corridors;
+mod distance;
 mod grid_pos;
+mod line;
+mod prefix_sums;
+mod rect;
+pub mod symmetry;
 
 use itertools::Itertools;
 use std::fmt::Debug;
 
-pub use self::grid_pos::GridPos;
+pub use self::compressed::CompressedGrid;
+pub use self::corridors::{compact_graph, Corridor, CorridorGraph};
+pub use self::distance::{Connectivity, Nearest};
+pub use self::grid_pos::{Coord, GridPos};
+pub use self::line::{segments_from_steps, Line};
+pub use self::prefix_sums::{PrefixSum, SummedAreaTable};
+pub use self::rect::Rect;
 pub use crate::pos;
 
+/// Reasons a grid cannot be constructed from a set of rows
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum GridError {
+    /// A row has a different length to the first row
+    RaggedRow {
+        row: usize,
+        expected: usize,
+        found: usize,
+    },
+}
+
+impl std::fmt::Display for GridError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            GridError::RaggedRow {
+                row,
+                expected,
+                found,
+            } => write!(
+                f,
+                "Grid row {row} has length {found} but expected length {expected}"
+            ),
+        }
+    }
+}
+
+impl std::error::Error for GridError {}
+
 /// A two dimensional grid of values
 #[derive(Clone, PartialEq, Eq)]
 pub struct Grid<T> {
@@ -27,15 +68,47 @@ pub struct Grid<T> {
 }
 
 impl<T> Grid<T> {
-    /// panics if grid is empty
+    /// Create a grid from a list of rows
+    /// panics if the rows are not all the same length
     pub fn new(grid: Vec<Vec<T>>) -> Self {
+        Self::try_new(grid).unwrap_or_else(|err| panic!("{err}"))
+    }
+
+    /// Create a grid from a list of rows
+    /// Returns an error if the rows are not all the same length. An empty list of
+    /// rows produces an empty grid.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use aoc::grid::{Grid, GridError};
+    /// let grid = Grid::try_new(vec![vec![1, 2, 3], vec![4, 5, 6]]).unwrap();
+    /// assert_eq!((grid.width, grid.height), (3, 2));
+    ///
+    /// let ragged = Grid::try_new(vec![vec![1, 2, 3], vec![4, 5]]);
+    /// assert_eq!(ragged, Err(GridError::RaggedRow { row: 1, expected: 3, found: 2 }));
+    /// ```
+    pub fn try_new(grid: Vec<Vec<T>>) -> Result<Self, GridError> {
         let height = grid.len();
-        let width = grid[0].len();
-        Self {
+        let width = grid.first().map_or(0, |row| row.len());
+        if let Some((row, found)) = grid
+            .iter()
+            .map(|row| row.len())
+            .enumerate()
+            .find(|&(_, len)| len != width)
+        {
+            return Err(GridError::RaggedRow {
+                row,
+                expected: width,
+                found,
+            });
+        }
+
+        Ok(Self {
             width,
             height,
             grid,
-        }
+        })
     }
 
     /// Create a grid of the specified size and fill it with the default cell value
@@ -44,15 +117,16 @@ impl<T> Grid<T> {
     ///
     /// ```
     /// use aoc::grid::Grid;
-    /// let grid: Grid<usize> = Grid::from_default(10, 10);
-    /// assert_eq!(grid.get((0, 0)), Some(0));
+    /// let grid: Grid<usize> = Grid::from_default(10, 4);
+    /// assert_eq!(grid.get((9, 3)), Some(0));
+    /// assert_eq!(grid.get((3, 9)), None);
     /// ```
     pub fn from_default(width: usize, height: usize) -> Self
     where
         T: Default,
     {
         let grid = (0..height)
-            .map(|_| (0..height).map(|_| Default::default()).collect())
+            .map(|_| (0..width).map(|_| Default::default()).collect())
             .collect();
         Self {
             width,
@@ -68,15 +142,16 @@ impl<T> Grid<T> {
     ///
     /// ```
     /// use aoc::grid::{Grid, GridPos};
-    /// let grid = Grid::from_fn(10, 10, |GridPos(x, y)| x + y);
+    /// let grid = Grid::from_fn(10, 4, |GridPos(x, y)| x + y);
     /// assert_eq!(grid.get((3, 2)), Some(5));
+    /// assert_eq!(grid.get((9, 3)), Some(12));
     /// ```
     pub fn from_fn<F>(width: usize, height: usize, cell_fn: F) -> Self
     where
         F: Fn(GridPos) -> T,
     {
         let grid = (0..height)
-            .map(|y| (0..height).map(|x| cell_fn(pos!(x, y))).collect())
+            .map(|y| (0..width).map(|x| cell_fn(pos!(x, y))).collect())
             .collect();
         Self {
             width,
@@ -144,6 +219,7 @@ impl<T> Grid<T> {
     /// grid.set((0, 0), 1);
     /// assert_eq!(grid.get((0, 0)), Some(1));
     /// ```
+    #[allow(clippy::multiple_bound_locations)]
     pub fn set<C: Into<(isize, isize)>>(&mut self, coords: C, value: T) -> Result<(), String>
     where
         C: Debug + Clone,
@@ -228,3 +304,70 @@ where
         Ok(())
     }
 }
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use proptest::prelude::*;
+
+    /// Check the reported dimensions against the backing rows
+    fn assert_dimensions<T>(grid: &Grid<T>, width: usize, height: usize) {
+        assert_eq!(grid.width, width);
+        assert_eq!(grid.height, height);
+        assert_eq!(grid.grid.len(), height);
+        assert!(grid.grid.iter().all(|row| row.len() == width));
+    }
+
+    #[test]
+    fn try_new_empty() {
+        let grid: Grid<u8> = Grid::try_new(vec![]).unwrap();
+        assert_dimensions(&grid, 0, 0);
+    }
+
+    #[test]
+    #[should_panic]
+    fn new_panics_on_ragged_rows() {
+        Grid::new(vec![vec![1], vec![1, 2]]);
+    }
+
+    proptest! {
+        #[test]
+        fn from_default_dimensions(width in 0..50usize, height in 0..50usize) {
+            let grid: Grid<u8> = Grid::from_default(width, height);
+            assert_dimensions(&grid, width, height);
+            prop_assert_eq!(grid.positions().count(), width * height);
+        }
+
+        #[test]
+        fn from_fn_dimensions(width in 0..50usize, height in 0..50usize) {
+            let grid = Grid::from_fn(width, height, |p| p);
+            assert_dimensions(&grid, width, height);
+            for p in grid.positions() {
+                prop_assert_eq!(grid.get(p), Some(p));
+            }
+        }
+
+        #[test]
+        fn try_new_rectangular(width in 0..50usize, height in 0..50usize) {
+            let grid = Grid::try_new(vec![vec![0u8; width]; height]).unwrap();
+            assert_dimensions(&grid, if height == 0 { 0 } else { width }, height);
+        }
+
+        #[test]
+        fn try_new_rejects_ragged(lens in prop::collection::vec(0..10usize, 1..10)) {
+            let rows = lens.iter().map(|&len| vec![0u8; len]).collect_vec();
+            match Grid::try_new(rows) {
+                Ok(grid) => {
+                    prop_assert!(lens.iter().all(|&len| len == lens[0]));
+                    assert_dimensions(&grid, lens[0], lens.len());
+                }
+                Err(GridError::RaggedRow { row, expected, found }) => {
+                    prop_assert_eq!(expected, lens[0]);
+                    prop_assert_eq!(found, lens[row]);
+                    prop_assert!(lens[..row].iter().all(|&len| len == expected));
+                    prop_assert_ne!(found, expected);
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,180 @@
+use std::ops::{Add, Range, Sub};
+
+use super::{Grid, GridPos};
+
+/// Running totals over a list of values, for answering range sums in constant time
+///
+/// # Examples
+/// ```
+/// use aoc::grid::PrefixSum;
+///
+/// // Empty columns count as a million, others as one
+/// let widths: PrefixSum<usize> = [1, 1_000_000, 1, 1].into_iter().collect();
+/// assert_eq!(widths.sum(1..3), 1_000_001);
+/// assert_eq!(widths.total(), 1_000_003);
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PrefixSum<T> {
+    /// The sum of every value before each index, plus a final entry for the total
+    totals: Vec<T>,
+}
+
+impl<T> FromIterator<T> for PrefixSum<T>
+where
+    T: Copy + Default + Add<Output = T>,
+{
+    fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
+        let mut total = T::default();
+        let totals = std::iter::once(total)
+            .chain(iter.into_iter().map(|value| {
+                total = total + value;
+                total
+            }))
+            .collect();
+        Self { totals }
+    }
+}
+
+impl<T> PrefixSum<T>
+where
+    T: Copy + Sub<Output = T>,
+{
+    /// The number of values that were summed
+    pub fn len(&self) -> usize {
+        self.totals.len() - 1
+    }
+
+    /// Whether there were no values to sum
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// The sum of every value before an index
+    /// panics if the index is greater than the number of values
+    pub fn prefix(&self, index: usize) -> T {
+        self.totals[index]
+    }
+
+    /// The sum of the values in a range of indices
+    /// panics if the range is out of bounds
+    pub fn sum(&self, range: Range<usize>) -> T {
+        self.totals[range.end] - self.totals[range.start]
+    }
+
+    /// The sum of every value
+    pub fn total(&self) -> T {
+        *self.totals.last().unwrap()
+    }
+}
+
+/// A summed-area table over a grid, for answering rectangle sums in constant time
+///
+/// # Examples
+/// ```
+/// use aoc::{pos, grid::{Grid, GridPos}};
+/// let grid = Grid::new(vec![vec![1, 2, 3], vec![4, 5, 6], vec![7, 8, 9]]);
+/// let sums = grid.prefix_sums();
+/// assert_eq!(sums.rect_sum(pos!(1, 1), pos!(2, 2)), 5 + 6 + 8 + 9);
+/// assert_eq!(sums.rect_sum(pos!(0, 2), pos!(2, 2)), 7 + 8 + 9);
+/// assert_eq!(sums.total(), 45);
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SummedAreaTable<T> {
+    /// The sum of every cell above and to the left of each position, with an extra leading
+    /// row and column of zeroes
+    totals: Grid<T>,
+}
+
+impl<T> SummedAreaTable<T>
+where
+    T: Copy + Add<Output = T> + Sub<Output = T>,
+{
+    /// The sum of every cell in the rectangle between two corners (inclusive)
+    /// panics if either corner is out of bounds
+    pub fn rect_sum(&self, from: GridPos, to: GridPos) -> T {
+        let (x0, x1) = (from.0.min(to.0), from.0.max(to.0) + 1);
+        let (y0, y1) = (from.1.min(to.1), from.1.max(to.1) + 1);
+        let at = |x, y| self.totals.get_unchecked((x, y));
+        at(x1, y1) + at(x0, y0) - at(x0, y1) - at(x1, y0)
+    }
+
+    /// The sum of every cell in the grid
+    pub fn total(&self) -> T {
+        self.totals.get_unchecked((
+            self.totals.width as isize - 1,
+            self.totals.height as isize - 1,
+        ))
+    }
+}
+
+impl<T> Grid<T>
+where
+    T: Copy + Default + Add<Output = T> + Sub<Output = T>,
+{
+    /// Build a summed-area table that can sum any rectangle of cells in constant time
+    pub fn prefix_sums(&self) -> SummedAreaTable<T> {
+        let mut totals = Grid::from_default(self.width + 1, self.height + 1);
+        for y in 0..self.height {
+            for x in 0..self.width {
+                totals.grid[y + 1][x + 1] =
+                    self.grid[y][x] + totals.grid[y][x + 1] + totals.grid[y + 1][x]
+                        - totals.grid[y][x];
+            }
+        }
+        SummedAreaTable { totals }
+    }
+
+    /// Build running totals along a single row
+    /// panics if the row is out of bounds
+    ///
+    /// # Examples
+    /// ```
+    /// use aoc::grid::Grid;
+    /// let grid = Grid::new(vec![vec![1, 2, 3], vec![4, 5, 6]]);
+    /// assert_eq!(grid.row_prefix_sums(1).sum(1..3), 11);
+    /// ```
+    pub fn row_prefix_sums(&self, y: usize) -> PrefixSum<T> {
+        self.grid[y].iter().copied().collect()
+    }
+
+    /// Build running totals down a single column
+    /// panics if the column is out of bounds
+    ///
+    /// # Examples
+    /// ```
+    /// use aoc::grid::Grid;
+    /// let grid = Grid::new(vec![vec![1, 2, 3], vec![4, 5, 6]]);
+    /// assert_eq!(grid.col_prefix_sums(2).total(), 9);
+    /// ```
+    pub fn col_prefix_sums(&self, x: usize) -> PrefixSum<T> {
+        assert!(x < self.width, "Column {x} is out of bounds");
+        self.grid.iter().map(|row| row[x]).collect()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::pos;
+    use itertools::Itertools;
+    use proptest::prelude::*;
+
+    proptest! {
+        #[test]
+        fn rect_sum_matches_brute_force(
+            rows in prop::collection::vec(prop::collection::vec(0..100usize, 6), 1..6),
+            a in (0..6isize, 0..6isize),
+            b in (0..6isize, 0..6isize),
+        ) {
+            let grid = Grid::new(rows);
+            let (a, b) = (pos!(a.0, a.1.min(grid.height as isize - 1)), pos!(b.0, b.1.min(grid.height as isize - 1)));
+            let expected: usize = (a.0.min(b.0)..=a.0.max(b.0))
+                .cartesian_product(a.1.min(b.1)..=a.1.max(b.1))
+                .map(|(x, y)| grid.get_unchecked((x, y)))
+                .sum();
+            let sums = grid.prefix_sums();
+            prop_assert_eq!(sums.rect_sum(a, b), expected);
+            prop_assert_eq!(sums.total(), grid.cells_iter().sum::<usize>());
+        }
+    }
+}
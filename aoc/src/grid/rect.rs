@@ -0,0 +1,217 @@
+use itertools::Itertools;
+
+use super::{Grid, GridPos};
+use crate::{direction::Dir, pos};
+
+/// An axis aligned rectangle of grid positions, with inclusive corners
+///
+/// # Examples
+/// ```
+/// use aoc::{pos, grid::{GridPos, Rect}};
+/// let rect = Rect::from_points([pos!(3, 1), pos!(0, 4), pos!(2, 2)]).unwrap();
+/// assert_eq!(rect, Rect::new(pos!(0, 1), pos!(3, 4)));
+/// assert_eq!((rect.width(), rect.height(), rect.area()), (4, 4, 16));
+/// assert!(rect.contains(pos!(3, 4)));
+/// assert!(!rect.contains(pos!(3, 5)));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Rect {
+    /// The top left corner
+    pub min: GridPos,
+
+    /// The bottom right corner
+    pub max: GridPos,
+}
+
+impl Rect {
+    /// Create a rectangle from any two opposite corners
+    pub fn new(a: GridPos, b: GridPos) -> Self {
+        Self {
+            min: pos!(a.0.min(b.0), a.1.min(b.1)),
+            max: pos!(a.0.max(b.0), a.1.max(b.1)),
+        }
+    }
+
+    /// The smallest rectangle containing every point
+    /// Returns None if there are no points
+    pub fn from_points<I: IntoIterator<Item = GridPos>>(points: I) -> Option<Self> {
+        let mut points = points.into_iter();
+        let first = points.next()?;
+        Some(points.fold(Self::new(first, first), |rect, p| {
+            rect.union(Self::new(p, p))
+        }))
+    }
+
+    /// The number of columns covered by the rectangle
+    pub fn width(&self) -> usize {
+        (self.max.0 - self.min.0 + 1).max(0) as usize
+    }
+
+    /// The number of rows covered by the rectangle
+    pub fn height(&self) -> usize {
+        (self.max.1 - self.min.1 + 1).max(0) as usize
+    }
+
+    /// The number of positions covered by the rectangle
+    pub fn area(&self) -> usize {
+        self.width() * self.height()
+    }
+
+    /// Whether the rectangle covers no positions
+    pub fn is_empty(&self) -> bool {
+        self.area() == 0
+    }
+
+    /// Whether a position lies inside the rectangle (or on its edge)
+    pub fn contains(&self, p: GridPos) -> bool {
+        (self.min.0..=self.max.0).contains(&p.0) && (self.min.1..=self.max.1).contains(&p.1)
+    }
+
+    /// The positions covered by both rectangles
+    /// Returns None if they don't overlap
+    ///
+    /// # Examples
+    /// ```
+    /// use aoc::{pos, grid::{GridPos, Rect}};
+    /// let a = Rect::new(pos!(0, 0), pos!(4, 4));
+    /// let b = Rect::new(pos!(3, 2), pos!(8, 8));
+    /// assert_eq!(a.intersect(b), Some(Rect::new(pos!(3, 2), pos!(4, 4))));
+    /// assert_eq!(a.intersect(Rect::new(pos!(5, 0), pos!(6, 0))), None);
+    /// ```
+    pub fn intersect(&self, other: Self) -> Option<Self> {
+        let rect = Self {
+            min: pos!(self.min.0.max(other.min.0), self.min.1.max(other.min.1)),
+            max: pos!(self.max.0.min(other.max.0), self.max.1.min(other.max.1)),
+        };
+        (!rect.is_empty()).then_some(rect)
+    }
+
+    /// The smallest rectangle containing both rectangles
+    pub fn union(&self, other: Self) -> Self {
+        Self {
+            min: pos!(self.min.0.min(other.min.0), self.min.1.min(other.min.1)),
+            max: pos!(self.max.0.max(other.max.0), self.max.1.max(other.max.1)),
+        }
+    }
+
+    /// Grow the rectangle by some amount on every side (or shrink it if negative)
+    ///
+    /// # Examples
+    /// ```
+    /// use aoc::{pos, grid::{GridPos, Rect}};
+    /// let rect = Rect::new(pos!(0, 0), pos!(2, 2)).expand(1);
+    /// assert_eq!(rect, Rect::new(pos!(-1, -1), pos!(3, 3)));
+    /// ```
+    pub fn expand(&self, amount: isize) -> Self {
+        Self {
+            min: pos!(self.min.0 - amount, self.min.1 - amount),
+            max: pos!(self.max.0 + amount, self.max.1 + amount),
+        }
+    }
+
+    /// The corners of the rectangle, clockwise from the top left
+    pub fn corners(&self) -> [GridPos; 4] {
+        [
+            self.min,
+            pos!(self.max.0, self.min.1),
+            self.max,
+            pos!(self.min.0, self.max.1),
+        ]
+    }
+
+    /// Every position in the rectangle in row-col order
+    pub fn positions(&self) -> impl Iterator<Item = GridPos> {
+        (self.min.1..=self.max.1)
+            .cartesian_product(self.min.0..=self.max.0)
+            .map(|(y, x)| pos!(x, y))
+    }
+
+    /// The positions along one side of the rectangle, from left to right or top to bottom
+    pub fn edge(&self, side: Dir) -> impl Iterator<Item = GridPos> {
+        let (min, max) = (self.min, self.max);
+        let (xs, ys) = match side {
+            Dir::North => (min.0..=max.0, min.1..=min.1),
+            Dir::South => (min.0..=max.0, max.1..=max.1),
+            Dir::West => (min.0..=min.0, min.1..=max.1),
+            Dir::East => (max.0..=max.0, min.1..=max.1),
+        };
+        ys.cartesian_product(xs).map(|(y, x)| pos!(x, y))
+    }
+
+    /// The positions along every side of the rectangle, tagged with the side they are on
+    /// Corners are included once for each side they are on
+    ///
+    /// # Examples
+    /// ```
+    /// use aoc::{pos, direction::Dir, grid::{GridPos, Rect}};
+    /// let rect = Rect::new(pos!(0, 0), pos!(2, 1));
+    /// assert_eq!(rect.edges().count(), 10);
+    /// assert!(rect.edges().any(|e| e == (Dir::East, pos!(2, 1))));
+    /// assert!(rect.edges().any(|e| e == (Dir::South, pos!(2, 1))));
+    /// ```
+    pub fn edges(&self) -> impl Iterator<Item = (Dir, GridPos)> {
+        let rect = *self;
+        Dir::ALL
+            .into_iter()
+            .flat_map(move |side| rect.edge(side).map(move |p| (side, p)))
+    }
+}
+
+impl<T> Grid<T> {
+    /// A rectangle covering every position in the grid
+    /// (empty if the grid is empty)
+    ///
+    /// # Examples
+    /// ```
+    /// use aoc::{pos, grid::{Grid, GridPos, Rect}};
+    /// let grid: Grid<u8> = Grid::from_default(5, 3);
+    /// assert_eq!(grid.bounds(), Rect::new(pos!(0, 0), pos!(4, 2)));
+    /// ```
+    pub fn bounds(&self) -> Rect {
+        Rect {
+            min: pos!(0, 0),
+            max: pos!(self.width as isize - 1, self.height as isize - 1),
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use proptest::prelude::*;
+
+    fn any_rect() -> impl Strategy<Value = Rect> {
+        (-10..10isize, -10..10isize, -10..10isize, -10..10isize)
+            .prop_map(|(x1, y1, x2, y2)| Rect::new(pos!(x1, y1), pos!(x2, y2)))
+    }
+
+    proptest! {
+        #[test]
+        fn positions_match_area(rect in any_rect()) {
+            prop_assert_eq!(rect.positions().count(), rect.area());
+            prop_assert!(rect.positions().all(|p| rect.contains(p)));
+            prop_assert_eq!(Rect::from_points(rect.positions()), Some(rect));
+        }
+
+        #[test]
+        fn intersect_and_union(a in any_rect(), b in any_rect(), x in -12..12isize, y in -12..12isize) {
+            let p = pos!(x, y);
+            let both = a.contains(p) && b.contains(p);
+            prop_assert_eq!(a.intersect(b).is_some_and(|r| r.contains(p)), both);
+            if a.contains(p) || b.contains(p) {
+                prop_assert!(a.union(b).contains(p));
+            }
+        }
+
+        #[test]
+        fn edges_surround_the_rect(rect in any_rect()) {
+            let outside = |p: GridPos| !rect.contains(p);
+            for (side, p) in rect.edges() {
+                prop_assert!(rect.contains(p));
+                prop_assert!(outside(p + side.into()));
+            }
+            prop_assert!(rect.corners().iter().all(|&c| rect.contains(c)));
+            prop_assert_eq!(rect.edges().count(), 2 * (rect.width() + rect.height()));
+        }
+    }
+}
@@ -0,0 +1,215 @@
+//! Finding mirror lines and rotational symmetry in grids
+//!
+//! # Examples
+//! ```
+//! use aoc::{direction::OrthDir, grid::{Grid, symmetry}};
+//!
+//! let grid = Grid::new(vec![
+//!     "#..#.".chars().collect(),
+//!     ".##..".chars().collect(),
+//!     "#..#.".chars().collect(),
+//! ]);
+//!
+//! // Columns 0..=1 mirror columns 2..=3
+//! let perfect: Vec<_> = symmetry::mirror_lines(&grid)
+//!     .into_iter()
+//!     .filter(|line| line.mismatches == 0)
+//!     .map(|line| (line.orientation, line.index))
+//!     .collect();
+//! assert_eq!(perfect, vec![(OrthDir::Vertical, 2)]);
+//! ```
+
+use itertools::Itertools;
+
+use super::{Grid, GridPos};
+use crate::{direction::OrthDir, pos};
+
+/// A line that mirrors a grid onto itself, possibly with some cells that don't match
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct MirrorLine {
+    /// `Horizontal` lines lie between two rows, `Vertical` lines lie between two columns
+    pub orientation: OrthDir,
+
+    /// The line lies just before this row or column
+    pub index: usize,
+
+    /// The number of pairs of cells that are different either side of the line
+    pub mismatches: usize,
+}
+
+/// Every pair of cells that are reflected onto each other by a mirror line
+fn mirrored_pairs<T>(
+    grid: &Grid<T>,
+    orientation: OrthDir,
+    index: usize,
+) -> impl Iterator<Item = (GridPos, GridPos)> + '_ {
+    let (span_count, span_len) = match orientation {
+        OrthDir::Horizontal => (grid.height, grid.width),
+        OrthDir::Vertical => (grid.width, grid.height),
+    };
+    (0..index.min(span_count))
+        .rev()
+        .zip(index..span_count)
+        .cartesian_product(0..span_len)
+        .map(move |((a, b), i)| match orientation {
+            OrthDir::Horizontal => (pos!(i, a), pos!(i, b)),
+            OrthDir::Vertical => (pos!(a, i), pos!(b, i)),
+        })
+}
+
+fn cell<T>(grid: &Grid<T>, pos: GridPos) -> &T {
+    &grid.grid[pos.1 as usize][pos.0 as usize]
+}
+
+/// Find the pairs of cells that break the symmetry of a mirror line
+///
+/// # Examples
+/// ```
+/// use aoc::{direction::OrthDir, pos, grid::{Grid, GridPos, symmetry}};
+/// let grid = Grid::new(vec![vec![1, 2], vec![1, 3], vec![5, 6]]);
+/// assert_eq!(
+///     symmetry::mirror_mismatches(&grid, OrthDir::Horizontal, 1),
+///     vec![(pos!(1, 0), pos!(1, 1))],
+/// );
+/// ```
+pub fn mirror_mismatches<T: Eq>(
+    grid: &Grid<T>,
+    orientation: OrthDir,
+    index: usize,
+) -> Vec<(GridPos, GridPos)> {
+    mirrored_pairs(grid, orientation, index)
+        .filter(|&(a, b)| cell(grid, a) != cell(grid, b))
+        .collect()
+}
+
+/// Find every horizontal and vertical mirror line in a grid along with how many pairs of
+/// cells don't match across it
+/// Horizontal lines are listed first, each in order of their index.
+pub fn mirror_lines<T: Eq>(grid: &Grid<T>) -> Vec<MirrorLine> {
+    let horizontal = (1..grid.height).map(|index| (OrthDir::Horizontal, index));
+    let vertical = (1..grid.width).map(|index| (OrthDir::Vertical, index));
+    horizontal
+        .chain(vertical)
+        .map(|(orientation, index)| MirrorLine {
+            orientation,
+            index,
+            mismatches: mirrored_pairs(grid, orientation, index)
+                .filter(|&(a, b)| cell(grid, a) != cell(grid, b))
+                .count(),
+        })
+        .collect()
+}
+
+/// Find the first mirror line with exactly the given number of mismatched pairs of cells
+///
+/// # Examples
+/// ```
+/// use aoc::{direction::OrthDir, grid::{Grid, symmetry}};
+/// let grid = Grid::new(vec![vec![1, 2, 2, 1], vec![3, 4, 4, 4]]);
+/// let line = symmetry::find_mirror_line(&grid, 1).unwrap();
+/// assert_eq!((line.orientation, line.index), (OrthDir::Vertical, 2));
+/// ```
+pub fn find_mirror_line<T: Eq>(grid: &Grid<T>, mismatches: usize) -> Option<MirrorLine> {
+    mirror_lines(grid)
+        .into_iter()
+        .find(|line| line.mismatches == mismatches)
+}
+
+/// Where a cell ends up after rotating the grid clockwise by some number of quarter turns
+/// Returns None if the rotated grid would have a different shape
+fn rotated<T>(grid: &Grid<T>, pos: GridPos, quarter_turns: usize) -> Option<GridPos> {
+    let (w, h) = (grid.width as isize - 1, grid.height as isize - 1);
+    match quarter_turns % 4 {
+        0 => Some(pos),
+        2 => Some(pos!(w - pos.0, h - pos.1)),
+        1 if w == h => Some(pos!(w - pos.1, pos.0)),
+        3 if w == h => Some(pos!(pos.1, h - pos.0)),
+        _ => None,
+    }
+}
+
+/// Find the cells that don't match after rotating a grid clockwise by some number of quarter
+/// turns, or None if the rotated grid would be a different shape
+///
+/// # Examples
+/// ```
+/// use aoc::{pos, grid::{Grid, GridPos, symmetry}};
+/// let grid = Grid::new(vec![vec![1, 2, 3], vec![4, 5, 6], vec![3, 2, 1]]);
+/// assert_eq!(symmetry::rotation_mismatches(&grid, 2), Some(vec![pos!(0, 1), pos!(2, 1)]));
+/// ```
+pub fn rotation_mismatches<T: Eq>(grid: &Grid<T>, quarter_turns: usize) -> Option<Vec<GridPos>> {
+    rotated(grid, pos!(0, 0), quarter_turns)?;
+    Some(
+        grid.positions()
+            .filter(|&pos| {
+                let target = rotated(grid, pos, quarter_turns).unwrap();
+                cell(grid, pos) != cell(grid, target)
+            })
+            .collect(),
+    )
+}
+
+/// The number of distinct quarter turn rotations that leave a grid unchanged (1, 2 or 4)
+///
+/// # Examples
+/// ```
+/// use aoc::grid::{Grid, symmetry};
+/// let pinwheel = Grid::new(vec![vec![1, 0, 1], vec![0, 2, 0], vec![1, 0, 1]]);
+/// assert_eq!(symmetry::rotational_order(&pinwheel), 4);
+///
+/// let domino = Grid::new(vec![vec![1, 2], vec![2, 1]]);
+/// assert_eq!(symmetry::rotational_order(&domino), 2);
+/// ```
+pub fn rotational_order<T: Eq>(grid: &Grid<T>) -> usize {
+    let symmetric = |turns| rotation_mismatches(grid, turns).is_some_and(|m| m.is_empty());
+    if symmetric(1) {
+        4
+    } else if symmetric(2) {
+        2
+    } else {
+        1
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn parse(text: &str) -> Grid<char> {
+        Grid::new(text.lines().map(|line| line.chars().collect()).collect())
+    }
+
+    #[test]
+    fn ash_and_rock_patterns() {
+        let first =
+            parse("#.##..##.\n..#.##.#.\n##......#\n##......#\n..#.##.#.\n..##..##.\n#.#.##.#.");
+        let second =
+            parse("#...##..#\n#....#..#\n..##..###\n#####.##.\n#####.##.\n..##..###\n#....#..#");
+
+        let line = find_mirror_line(&first, 0).unwrap();
+        assert_eq!((line.orientation, line.index), (OrthDir::Vertical, 5));
+        let line = find_mirror_line(&second, 0).unwrap();
+        assert_eq!((line.orientation, line.index), (OrthDir::Horizontal, 4));
+
+        // Fixing a single smudge moves the line
+        let line = find_mirror_line(&first, 1).unwrap();
+        assert_eq!((line.orientation, line.index), (OrthDir::Horizontal, 3));
+        assert_eq!(
+            mirror_mismatches(&first, OrthDir::Horizontal, 3),
+            vec![(pos!(0, 0), pos!(0, 5))]
+        );
+        let line = find_mirror_line(&second, 1).unwrap();
+        assert_eq!((line.orientation, line.index), (OrthDir::Horizontal, 1));
+    }
+
+    #[test]
+    fn rotation_of_non_square_grid() {
+        let grid = parse("ab\ncd\nba");
+        assert_eq!(rotation_mismatches(&grid, 1), None);
+        assert_eq!(
+            rotation_mismatches(&grid, 2),
+            Some(vec![pos!(0, 1), pos!(1, 1)])
+        );
+        assert_eq!(rotational_order(&grid), 1);
+    }
+}
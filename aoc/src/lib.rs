@@ -1,2 +1,7 @@
 pub mod direction;
+pub mod geometry;
 pub mod grid;
+pub mod math;
+pub mod parse;
+pub mod ranges;
+pub mod tiles;
@@ -0,0 +1,348 @@
+//! Number theory helpers: greatest common divisors, modular arithmetic and the Chinese
+//! Remainder Theorem
+//!
+//! [`gcd`] and [`lcm`] work with any primitive integer type. The modular arithmetic functions
+//! work in `i128` so that large moduli don't overflow in intermediate products.
+//!
+//! # Examples
+//! ```
+//! use aoc::math;
+//!
+//! // Cycles of length 4, 6 and 10 all line up again after 60 steps
+//! assert_eq!(math::lcm_all([4u64, 6, 10]), 60);
+//!
+//! // x = 2 (mod 3), x = 3 (mod 5), x = 2 (mod 7)
+//! assert_eq!(math::crt(&[(2, 3), (3, 5), (2, 7)]), Some((23, 105)));
+//! ```
+
+pub mod poly;
+mod quadratic;
+
+use std::ops::{Add, Div, Mul, Rem, Sub};
+
+pub use self::quadratic::quadratic_integer_range;
+
+/// A primitive integer type, signed or unsigned
+pub trait Integer:
+    Copy
+    + Ord
+    + Add<Output = Self>
+    + Sub<Output = Self>
+    + Div<Output = Self>
+    + Mul<Output = Self>
+    + Rem<Output = Self>
+    + std::fmt::Debug
+{
+    const ZERO: Self;
+    const ONE: Self;
+
+    /// The absolute value (the value itself for unsigned types)
+    /// Returns None if it doesn't fit in the type.
+    fn checked_abs(self) -> Option<Self>;
+    fn checked_mul(self, rhs: Self) -> Option<Self>;
+    fn checked_rem(self, rhs: Self) -> Option<Self>;
+}
+
+macro_rules! impl_integer {
+    (signed: $($t:ty),*; unsigned: $($u:ty),*) => {
+        $(
+            impl Integer for $t {
+                const ZERO: Self = 0;
+                const ONE: Self = 1;
+
+                fn checked_abs(self) -> Option<Self> {
+                    <$t>::checked_abs(self)
+                }
+
+                fn checked_mul(self, rhs: Self) -> Option<Self> {
+                    <$t>::checked_mul(self, rhs)
+                }
+
+                fn checked_rem(self, rhs: Self) -> Option<Self> {
+                    <$t>::checked_rem(self, rhs)
+                }
+            }
+        )*
+        $(
+            impl Integer for $u {
+                const ZERO: Self = 0;
+                const ONE: Self = 1;
+
+                fn checked_abs(self) -> Option<Self> {
+                    Some(self)
+                }
+
+                fn checked_mul(self, rhs: Self) -> Option<Self> {
+                    <$u>::checked_mul(self, rhs)
+                }
+
+                fn checked_rem(self, rhs: Self) -> Option<Self> {
+                    <$u>::checked_rem(self, rhs)
+                }
+            }
+        )*
+    };
+}
+
+impl_integer!(signed: i8, i16, i32, i64, i128, isize; unsigned: u8, u16, u32, u64, u128, usize);
+
+/// The greatest common divisor of two integers, which is never negative
+/// Returns None if it doesn't fit in the type, which can only happen for signed types when
+/// it is `MIN.abs()`.
+///
+/// # Examples
+/// ```
+/// use aoc::math::checked_gcd;
+/// assert_eq!(checked_gcd(-4i64, 6), Some(2));
+/// assert_eq!(checked_gcd(i64::MIN, 0), None);
+/// assert_eq!(checked_gcd(i64::MIN, 6), Some(2));
+/// ```
+pub fn checked_gcd<T: Integer>(mut a: T, mut b: T) -> Option<T> {
+    while b != T::ZERO {
+        // The only remainder that overflows is `MIN % -1`, which is 0
+        (a, b) = (b, a.checked_rem(b).unwrap_or(T::ZERO));
+    }
+    a.checked_abs()
+}
+
+/// The greatest common divisor of two integers, which is never negative
+/// `gcd(0, 0)` is 0.
+///
+/// # Panics
+/// If the result doesn't fit in the type, e.g `gcd(i64::MIN, 0)`
+///
+/// # Examples
+/// ```
+/// use aoc::math::gcd;
+/// assert_eq!(gcd(12u32, 18), 6);
+/// assert_eq!(gcd(-4i64, 6), 2);
+/// assert_eq!(gcd(0, 7), 7);
+/// ```
+pub fn gcd<T: Integer>(a: T, b: T) -> T {
+    checked_gcd(a, b).unwrap_or_else(|| panic!("gcd of {a:?} and {b:?} overflows"))
+}
+
+/// The least common multiple of two integers, which is never negative
+/// Returns None if it doesn't fit in the type.
+///
+/// # Examples
+/// ```
+/// use aoc::math::checked_lcm;
+/// assert_eq!(checked_lcm(-4i64, 6), Some(12));
+/// assert_eq!(checked_lcm(i64::MIN, 1), None);
+/// assert_eq!(checked_lcm(u64::MAX, u64::MAX - 1), None);
+/// ```
+pub fn checked_lcm<T: Integer>(a: T, b: T) -> Option<T> {
+    if a == T::ZERO || b == T::ZERO {
+        return Some(T::ZERO);
+    }
+    // Divide first so only the result itself can overflow
+    (a / checked_gcd(a, b)?)
+        .checked_mul(b)
+        .and_then(Integer::checked_abs)
+}
+
+/// The least common multiple of two integers, which is never negative
+///
+/// # Panics
+/// If the result doesn't fit in the type
+///
+/// # Examples
+/// ```
+/// use aoc::math::lcm;
+/// assert_eq!(lcm(4u8, 6), 12);
+/// // 20 * 30 doesn't fit in a u8, but the lcm does
+/// assert_eq!(lcm(20u8, 30), 60);
+/// ```
+pub fn lcm<T: Integer>(a: T, b: T) -> T {
+    checked_lcm(a, b).unwrap_or_else(|| panic!("lcm of {a:?} and {b:?} overflows"))
+}
+
+/// The greatest common divisor of every integer, or 0 if there are none
+pub fn gcd_all<T: Integer, I: IntoIterator<Item = T>>(values: I) -> T {
+    values.into_iter().fold(T::ZERO, gcd)
+}
+
+/// The least common multiple of every integer, or 1 if there are none
+///
+/// # Panics
+/// If the result doesn't fit in the type
+pub fn lcm_all<T: Integer, I: IntoIterator<Item = T>>(values: I) -> T {
+    values.into_iter().fold(T::ONE, lcm)
+}
+
+/// Find `(g, x, y)` such that `a * x + b * y = g`, where `g` is the greatest common divisor of
+/// `a` and `b`
+///
+/// # Examples
+/// ```
+/// use aoc::math::extended_gcd;
+/// let (g, x, y) = extended_gcd(240, 46);
+/// assert_eq!(g, 2);
+/// assert_eq!(240 * x + 46 * y, 2);
+/// ```
+pub fn extended_gcd(a: i128, b: i128) -> (i128, i128, i128) {
+    let (mut old_r, mut r) = (a, b);
+    let (mut old_x, mut x) = (1, 0);
+    let (mut old_y, mut y) = (0, 1);
+    while r != 0 {
+        let q = old_r / r;
+        (old_r, r) = (r, old_r - q * r);
+        (old_x, x) = (x, old_x - q * x);
+        (old_y, y) = (y, old_y - q * y);
+    }
+    if old_r < 0 {
+        (-old_r, -old_x, -old_y)
+    } else {
+        (old_r, old_x, old_y)
+    }
+}
+
+/// Multiply two values modulo `m` without overflowing, for any `m` that fits in an `i128`
+fn mul_mod(a: i128, b: i128, m: i128) -> i128 {
+    let (a, b) = (a.rem_euclid(m), b.rem_euclid(m));
+    if let Some(product) = a.checked_mul(b) {
+        return product % m;
+    }
+
+    // Fall back to doubling and adding, which never exceeds 2m
+    let (m, mut a, mut b) = (m as u128, a as u128, b as u128);
+    let mut result = 0;
+    while b > 0 {
+        if b & 1 == 1 {
+            result = (result + a) % m;
+        }
+        a = (a << 1) % m;
+        b >>= 1;
+    }
+    result as i128
+}
+
+/// The value `x` in `0..m` such that `a * x = 1 (mod m)`
+/// Returns None if `a` and `m` aren't coprime, or `m` isn't positive.
+///
+/// # Examples
+/// ```
+/// use aoc::math::mod_inverse;
+/// assert_eq!(mod_inverse(3, 11), Some(4));
+/// assert_eq!(mod_inverse(-3, 11), Some(7));
+/// assert_eq!(mod_inverse(4, 10), None);
+/// ```
+pub fn mod_inverse(a: i128, m: i128) -> Option<i128> {
+    if m <= 0 {
+        return None;
+    }
+    let (g, x, _) = extended_gcd(a.rem_euclid(m), m);
+    (g == 1).then(|| x.rem_euclid(m))
+}
+
+/// Raise `base` to the power `exp` modulo `m`, giving a value in `0..m`
+///
+/// # Panics
+/// If `m` isn't positive
+///
+/// # Examples
+/// ```
+/// use aoc::math::mod_pow;
+/// assert_eq!(mod_pow(4, 13, 497), 445);
+/// assert_eq!(mod_pow(-2, 3, 5), 2);
+/// assert_eq!(mod_pow(2, 127, i128::MAX), 1);
+/// ```
+pub fn mod_pow(base: i128, mut exp: u128, m: i128) -> i128 {
+    assert!(m > 0, "modulus must be positive, got {m}");
+    let mut base = base.rem_euclid(m);
+    let mut result = 1 % m;
+    while exp > 0 {
+        if exp & 1 == 1 {
+            result = mul_mod(result, base, m);
+        }
+        base = mul_mod(base, base, m);
+        exp >>= 1;
+    }
+    result
+}
+
+/// Solve a system of congruences `x = residue (mod modulus)`, where the moduli don't need to be
+/// coprime
+///
+/// Returns the smallest non-negative solution along with the lcm of the moduli, so every
+/// solution is `x + k * lcm`. Returns None if the congruences contradict each other, any
+/// modulus isn't positive, or the lcm doesn't fit in an `i128`.
+///
+/// # Examples
+/// ```
+/// use aoc::math::crt;
+/// assert_eq!(crt(&[(2, 4), (4, 6)]), Some((10, 12)));
+/// assert_eq!(crt(&[(1, 4), (2, 6)]), None);
+/// assert_eq!(crt(&[]), Some((0, 1)));
+/// ```
+pub fn crt(congruences: &[(i128, i128)]) -> Option<(i128, i128)> {
+    congruences
+        .iter()
+        .try_fold((0, 1), |(x, m), &(residue, modulus)| {
+            if modulus <= 0 {
+                return None;
+            }
+            let residue = residue.rem_euclid(modulus);
+            let g = gcd(m, modulus);
+            let diff = residue - x;
+            if diff % g != 0 {
+                return None;
+            }
+
+            // Solve x + m * k = residue (mod modulus) for k
+            let step = modulus / g;
+            let k = mul_mod(diff / g, mod_inverse(m / g, step)?, step);
+            let combined = checked_lcm(m, modulus)?;
+            Some(((x + mul_mod(m, k, combined)).rem_euclid(combined), combined))
+        })
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use proptest::prelude::*;
+
+    proptest! {
+        #[test]
+        fn gcd_and_lcm_divide(a in -1000..1000i64, b in -1000..1000i64) {
+            let (g, l) = (gcd(a, b), lcm(a, b));
+            prop_assert!(g >= 0 && l >= 0);
+            if g != 0 {
+                prop_assert_eq!((a % g, b % g), (0, 0));
+                prop_assert_eq!(g * l, (a * b).abs());
+            }
+            let (eg, x, y) = extended_gcd(a as i128, b as i128);
+            prop_assert_eq!(eg, g as i128);
+            prop_assert_eq!(a as i128 * x + b as i128 * y, eg);
+        }
+
+        #[test]
+        fn mod_pow_matches_repeated_multiplication(base in -50..50i128, exp in 0..20u128, m in 1..100i128) {
+            let expected = (0..exp).fold(1 % m, |acc, _| (acc * base).rem_euclid(m));
+            prop_assert_eq!(mod_pow(base, exp, m), expected);
+        }
+
+        #[test]
+        fn crt_matches_brute_force(congruences in prop::collection::vec((0..30i128, 1..12i128), 0..4)) {
+            let modulus = congruences.iter().fold(1, |l, &(_, m)| lcm(l, m));
+            let brute = (0..modulus)
+                .find(|x| congruences.iter().all(|&(r, m)| x % m == r % m));
+            prop_assert_eq!(crt(&congruences), brute.map(|x| (x, modulus)));
+        }
+    }
+
+    #[test]
+    fn large_moduli() {
+        let m = (1 << 100) + 277;
+        assert_eq!(mul_mod(m - 1, m - 1, m), 1);
+        assert_eq!(checked_lcm(u64::MAX, u64::MAX - 1), None);
+        assert_eq!(checked_lcm(i64::MIN, 1), None);
+        assert_eq!(checked_lcm(i64::MIN, 2), None);
+        assert_eq!(checked_gcd(i64::MIN, 0), None);
+        assert_eq!(checked_gcd(i64::MIN, -1), Some(1));
+        assert_eq!(checked_gcd(i8::MIN, i8::MIN), None);
+        assert_eq!(gcd_all([12, 18, 30]), 6);
+        assert_eq!(lcm_all(Vec::<u32>::new()), 1);
+    }
+}
@@ -0,0 +1,205 @@
+//! Fitting polynomials to sequences and extrapolating them
+//!
+//! [`Polynomial`] fits values sampled at `0, 1, 2, ...` using finite differences, and
+//! [`lagrange`] evaluates the polynomial through points at any positions. All arithmetic is
+//! exact, and gives None rather than overflowing.
+//!
+//! # Examples
+//! ```
+//! use aoc::math::poly::Polynomial;
+//!
+//! let squares = Polynomial::fit([0, 1, 4, 9, 16]).unwrap();
+//! assert_eq!(squares.degree(), 2);
+//! assert_eq!(squares.at(5), Some(25));
+//! assert_eq!(squares.at(-3), Some(9));
+//! assert_eq!(squares.at(1_000_000), Some(1_000_000_000_000));
+//! ```
+
+use super::gcd;
+
+/// The lowest degree polynomial through a sequence of values sampled at `0, 1, 2, ...`
+///
+/// Stored in Newton's forward difference form, as the first value of each row of the
+/// difference table.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Polynomial {
+    differences: Vec<i128>,
+}
+
+impl Polynomial {
+    /// Fit a polynomial to a sequence of values
+    /// Returns None if there are no values, or the differences overflow.
+    pub fn fit<T, I>(values: I) -> Option<Self>
+    where
+        T: Into<i128>,
+        I: IntoIterator<Item = T>,
+    {
+        let mut row: Vec<i128> = values.into_iter().map(Into::into).collect();
+        let mut differences = Vec::with_capacity(row.len());
+        while let Some(&first) = row.first() {
+            differences.push(first);
+            row = row
+                .windows(2)
+                .map(|w| w[1].checked_sub(w[0]))
+                .collect::<Option<_>>()?;
+        }
+
+        // Trailing zero differences don't contribute
+        while differences.len() > 1 && differences.last() == Some(&0) {
+            differences.pop();
+        }
+        (!differences.is_empty()).then_some(Self { differences })
+    }
+
+    /// The degree of the polynomial, where constants (including zero) have degree 0
+    pub fn degree(&self) -> usize {
+        self.differences.len() - 1
+    }
+
+    /// Evaluate the polynomial at any position, including before the first sample
+    /// Returns None if the result doesn't fit in an `i128`.
+    ///
+    /// # Examples
+    /// ```
+    /// use aoc::math::poly::Polynomial;
+    /// let history = Polynomial::fit([10, 13, 16, 21, 30, 45]).unwrap();
+    /// assert_eq!(history.at(6), Some(68));
+    /// assert_eq!(history.at(-1), Some(5));
+    /// ```
+    pub fn at(&self, x: i128) -> Option<i128> {
+        // Sum each difference times the binomial coefficient (x choose j)
+        let mut binomial: i128 = 1;
+        let mut total: i128 = 0;
+        for (j, &difference) in self.differences.iter().enumerate() {
+            if j > 0 {
+                // Exact, as the product of j consecutive integers is divisible by j!
+                let j = j as i128;
+                binomial = binomial.checked_mul(x.checked_sub(j - 1)?)? / j;
+            }
+            total = total.checked_add(difference.checked_mul(binomial)?)?;
+        }
+        Some(total)
+    }
+}
+
+/// An exact fraction in lowest terms, with a positive denominator
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Fraction {
+    pub num: i128,
+    pub den: i128,
+}
+
+impl Fraction {
+    /// Create a fraction in lowest terms
+    /// Returns None if the denominator is zero.
+    pub fn new(num: i128, den: i128) -> Option<Self> {
+        if den == 0 {
+            return None;
+        }
+        let g = gcd(num, den) * den.signum();
+        Some(Self {
+            num: num / g,
+            den: den / g,
+        })
+    }
+
+    /// The value as an integer, if it is one
+    pub fn to_integer(&self) -> Option<i128> {
+        (self.den == 1).then_some(self.num)
+    }
+
+    fn checked_add(self, other: Self) -> Option<Self> {
+        let g = gcd(self.den, other.den);
+        let den = (self.den / g).checked_mul(other.den)?;
+        let num = self
+            .num
+            .checked_mul(other.den / g)?
+            .checked_add(other.num.checked_mul(self.den / g)?)?;
+        Self::new(num, den)
+    }
+}
+
+/// Evaluate the lowest degree polynomial through some points at another position, using
+/// Lagrange interpolation
+/// Returns None if two points share a position, or the arithmetic overflows.
+///
+/// # Examples
+/// ```
+/// use aoc::math::poly::{lagrange, Fraction};
+/// // Points on y = x² sampled every 131 steps
+/// let points = [(65, 4225), (196, 38416), (327, 106929)];
+/// assert_eq!(lagrange(&points, 26501365).and_then(|f| f.to_integer()), Some(702322346863225));
+///
+/// // Points on y = x / 2
+/// assert_eq!(lagrange(&[(0, 0), (2, 1)], 1), Fraction::new(1, 2));
+/// ```
+pub fn lagrange(points: &[(i128, i128)], x: i128) -> Option<Fraction> {
+    points
+        .iter()
+        .enumerate()
+        .try_fold(Fraction::new(0, 1)?, |total, (i, &(xi, yi))| {
+            let (num, den) = points
+                .iter()
+                .enumerate()
+                .filter(|&(j, _)| j != i)
+                .try_fold((yi, 1i128), |(num, den), (_, &(xj, _))| {
+                    // Reduce as we go to keep the products small
+                    let term = Fraction::new(x.checked_sub(xj)?, xi.checked_sub(xj)?)?;
+                    let g = gcd(num, term.den);
+                    let h = gcd(term.num, den);
+                    Some((
+                        (num / g).checked_mul(term.num / h)?,
+                        (den / h).checked_mul(term.den / g)?,
+                    ))
+                })?;
+            total.checked_add(Fraction::new(num, den)?)
+        })
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use proptest::prelude::*;
+
+    fn evaluate(coefficients: &[i128], x: i128) -> i128 {
+        coefficients.iter().rev().fold(0, |acc, &c| acc * x + c)
+    }
+
+    proptest! {
+        #[test]
+        fn fits_random_polynomials(
+            coefficients in prop::collection::vec(-20..20i128, 1..5),
+            extra in 1..4usize,
+            x in -50..50i128,
+        ) {
+            let degree = coefficients.iter().rposition(|&c| c != 0).unwrap_or(0);
+            let values: Vec<_> = (0..(degree + extra) as i128).map(|x| evaluate(&coefficients, x)).collect();
+
+            let poly = Polynomial::fit(values.iter().copied()).unwrap();
+            prop_assert_eq!(poly.degree(), degree);
+            prop_assert_eq!(poly.at(x), Some(evaluate(&coefficients, x)));
+
+            // Sample at scattered positions instead
+            let points: Vec<_> = (0..=degree as i128)
+                .map(|i| (3 * i - 7, evaluate(&coefficients, 3 * i - 7)))
+                .collect();
+            prop_assert_eq!(
+                lagrange(&points, x).and_then(|f| f.to_integer()),
+                Some(evaluate(&coefficients, x))
+            );
+        }
+    }
+
+    #[test]
+    fn degenerate_inputs() {
+        assert_eq!(Polynomial::fit(Vec::<i64>::new()), None);
+        assert_eq!(Polynomial::fit([0, 0, 0]).unwrap().degree(), 0);
+        assert_eq!(Polynomial::fit([7]).unwrap().at(-100), Some(7));
+        assert_eq!(
+            Polynomial::fit([0u64, 1, 8, 27]).unwrap().at(i128::MAX / 2),
+            None
+        );
+        assert_eq!(lagrange(&[(1, 1), (1, 2)], 0), None);
+        assert_eq!(lagrange(&[], 5), Fraction::new(0, 1));
+    }
+}
@@ -0,0 +1,105 @@
+use std::{cmp::Ordering, ops::RangeInclusive};
+
+/// Find the integers `x` where `a * x² + b * x + c` compares to zero as `cmp`, using only
+/// integer arithmetic
+///
+/// Gives an empty range if there are no solutions. For non-strict inequalities use
+/// `c + 1` or `c - 1`, e.g `f(x) >= 0` is the same as `f(x) + 1 > 0` for integers.
+/// `b²` and `4ac` must fit in an `i128`.
+///
+/// # Panics
+/// If the solutions aren't a bounded range, which happens when `cmp` is `Equal`, or the
+/// parabola opens towards the solutions (or is a non-zero line)
+///
+/// # Examples
+/// ```
+/// use std::cmp::Ordering;
+/// use aoc::math::quadratic_integer_range;
+///
+/// // Holding the button for x ms in a 7 ms race travels (7 - x) * x mm, which beats 9 mm when
+/// // -x² + 7x - 9 > 0
+/// assert_eq!(quadratic_integer_range(-1, 7, -9, Ordering::Greater), 2..=5);
+///
+/// // x² - 4 < 0
+/// assert_eq!(quadratic_integer_range(1, 0, -4, Ordering::Less), -1..=1);
+/// assert!(quadratic_integer_range(1, 0, 4, Ordering::Less).is_empty());
+/// ```
+pub fn quadratic_integer_range(a: i128, b: i128, c: i128, cmp: Ordering) -> RangeInclusive<i128> {
+    // Flip the signs so that we're solving a * x² + b * x + c < 0
+    let (a, b, c) = match cmp {
+        Ordering::Less => (a, b, c),
+        Ordering::Greater => (-a, -b, -c),
+        Ordering::Equal => panic!("the roots of a quadratic aren't a range of integers"),
+    };
+    let f = |x: i128| a * x * x + b * x + c;
+    let empty = RangeInclusive::new(1, 0);
+
+    if a == 0 && b == 0 {
+        assert!(c >= 0, "every integer is a solution");
+        return empty;
+    }
+    assert!(a > 0, "the solutions are unbounded");
+
+    let discriminant = b * b - 4 * a * c;
+    if discriminant <= 0 {
+        return empty;
+    }
+
+    // Start from the roots rounded inwards, which may be off by one either way because of
+    // rounding down the square root
+    let root = (discriminant as u128).isqrt() as i128;
+    let mut low = (-b - root).div_euclid(2 * a) + 1;
+    let mut high = (-b + root).div_euclid(2 * a);
+    while f(low - 1) < 0 {
+        low -= 1;
+    }
+    while low <= high && f(low) >= 0 {
+        low += 1;
+    }
+    while f(high + 1) < 0 {
+        high += 1;
+    }
+    while high >= low && f(high) >= 0 {
+        high -= 1;
+    }
+
+    if low > high {
+        empty
+    } else {
+        low..=high
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use proptest::prelude::*;
+
+    proptest! {
+        #[test]
+        fn matches_brute_force(a in 1..20i128, b in -200..200i128, c in -500..500i128, greater: bool) {
+            let (a, b, c, cmp) = if greater {
+                (-a, -b, -c, Ordering::Greater)
+            } else {
+                (a, b, c, Ordering::Less)
+            };
+            let expected: Vec<_> = (-300..300)
+                .filter(|&x| (a * x * x + b * x + c).cmp(&0) == cmp)
+                .collect();
+            let range: Vec<_> = quadratic_integer_range(a, b, c, cmp).collect();
+            prop_assert_eq!(range, expected);
+        }
+    }
+
+    #[test]
+    fn long_race() {
+        // The second part of the 2023 day 6 sample, and a race long enough that iterating
+        // over every hold time would be too slow
+        let range = quadratic_integer_range(-1, 71530, -940200, Ordering::Greater);
+        assert_eq!(range.end() - range.start() + 1, 71503);
+
+        let time = 100_000_000_000_000;
+        let range = quadratic_integer_range(-1, time, -(time * time / 4 - 1), Ordering::Greater);
+        assert_eq!(range, time / 2..=time / 2);
+    }
+}
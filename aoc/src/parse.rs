@@ -0,0 +1,115 @@
+//! Nom combinators for parsing common values straight out of puzzle text
+//!
+//! # Examples
+//! ```
+//! use aoc::{parse, pos, grid::GridPos};
+//! use nom::{character::complete::space1, multi::separated_list1};
+//!
+//! let (_, path) = separated_list1(space1, parse::grid_pos)("0,0 3,4 (5, -1)").unwrap();
+//! assert_eq!(path, vec![pos!(0, 0), pos!(3, 4), pos!(5, -1)]);
+//! ```
+
+use nom::{
+    branch::alt,
+    character::complete::{alpha1, anychar, char, digit1, one_of, space0},
+    combinator::{map_opt, map_res, opt, recognize},
+    sequence::{delimited, pair, separated_pair, tuple},
+    IResult,
+};
+
+use crate::{
+    direction::{Dir, Notation},
+    grid::{Coord, GridPos},
+};
+
+/// Parse a signed integer coordinate, e.g `-12`
+pub fn coord<T: Coord>(input: &str) -> IResult<&str, T> {
+    map_res(recognize(pair(opt(one_of("+-")), digit1)), str::parse)(input)
+}
+
+/// Parse a position written as `x,y`, or as `(x, y)` with optional spaces
+pub fn grid_pos<T: Coord>(input: &str) -> IResult<&str, GridPos<T>> {
+    let bare = || separated_pair(coord, tuple((space0, char(','), space0)), coord);
+    let (input, (x, y)) = alt((
+        delimited(pair(char('('), space0), bare(), pair(space0, char(')'))),
+        bare(),
+    ))(input)?;
+    Ok((input, GridPos(x, y)))
+}
+
+/// Parse a direction written as a single character in any single character notation
+/// Accepts `U`/`D`/`L`/`R`, `N`/`E`/`S`/`W` and `^`/`v`/`<`/`>`, ignoring case
+///
+/// # Examples
+/// ```
+/// use aoc::{direction::Dir, parse};
+/// use nom::multi::many1;
+///
+/// let (_, dirs) = many1(parse::dir)("UR<S").unwrap();
+/// assert_eq!(dirs, vec![Dir::North, Dir::East, Dir::West, Dir::South]);
+/// ```
+pub fn dir(input: &str) -> IResult<&str, Dir> {
+    map_opt(anychar, |c| {
+        [Notation::Udlr, Notation::Compass, Notation::Arrow]
+            .into_iter()
+            .find_map(|notation| Dir::from_char(c, notation).ok())
+    })(input)
+}
+
+/// Parse a direction written in a particular notation (ignoring case)
+/// Single character notations read exactly one character, so runs like `LLR` can be parsed.
+///
+/// # Examples
+/// ```
+/// use aoc::{direction::{Dir, Notation}, parse};
+/// use nom::{character::complete::space1, multi::separated_list1};
+///
+/// let mut dirs = separated_list1(space1, parse::dir_in(Notation::UdlrWord));
+/// assert_eq!(dirs("up left"), Ok(("", vec![Dir::North, Dir::West])));
+/// ```
+pub fn dir_in(notation: Notation) -> impl FnMut(&str) -> IResult<&str, Dir> {
+    move |input| match notation {
+        Notation::UdlrWord | Notation::CompassWord => {
+            map_res(alpha1, |s| Dir::parse(s, notation))(input)
+        }
+        Notation::Udlr | Notation::Compass | Notation::Arrow => {
+            map_opt(anychar, |c| Dir::from_char(c, notation).ok())(input)
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::pos;
+
+    #[test]
+    fn positions() {
+        assert_eq!(grid_pos("12,-3 rest"), Ok((" rest", pos!(12, -3))));
+        assert_eq!(grid_pos("( 1 , 2 )"), Ok(("", pos!(1, 2))));
+        assert!(grid_pos::<isize>("(1,2").is_err());
+        assert!(grid_pos::<i8>("300,0").is_err());
+        assert_eq!(grid_pos("+7,0"), Ok(("", pos!(7, 0; i128))));
+    }
+
+    #[test]
+    fn runs_of_directions() {
+        use nom::multi::many1;
+
+        assert_eq!(
+            many1(dir_in(Notation::Udlr))("LLR\n"),
+            Ok(("\n", vec![Dir::West, Dir::West, Dir::East]))
+        );
+        assert_eq!(
+            many1(dir_in(Notation::Arrow))("^>x"),
+            Ok(("x", vec![Dir::North, Dir::East]))
+        );
+        assert_eq!(
+            dir_in(Notation::CompassWord)("Southward"),
+            Err(nom::Err::Error(nom::error::Error::new(
+                "Southward",
+                nom::error::ErrorKind::MapRes
+            )))
+        );
+    }
+}
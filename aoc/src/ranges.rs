@@ -0,0 +1,327 @@
+//! Sets of integers stored as ranges, and maps that shift ranges of integers around
+//!
+//! Both work with half-open ranges of any primitive integer type, so huge ranges can be
+//! processed without visiting each value.
+//!
+//! # Examples
+//! ```
+//! use aoc::ranges::{IntervalSet, RangeMap};
+//!
+//! let seeds: IntervalSet<u64> = [79..93, 55..68].into_iter().collect();
+//! let soil: RangeMap<u64> = [(98..100, 50), (50..98, 52)].into_iter().collect();
+//! let mapped = soil.map_set(&seeds);
+//! assert_eq!(mapped.ranges(), [57..70, 81..95]);
+//! ```
+
+use std::ops::Range;
+
+use crate::math::Integer;
+
+/// A set of integers, stored as sorted ranges that don't touch or overlap
+///
+/// # Examples
+/// ```
+/// use aoc::ranges::IntervalSet;
+/// let a: IntervalSet<i64> = [0..10, 5..15, 20..25].into_iter().collect();
+/// assert_eq!(a.ranges(), [0..15, 20..25]);
+/// assert_eq!(a.len(), 20);
+///
+/// let b = IntervalSet::from(12..22);
+/// assert_eq!(a.intersection(&b).ranges(), [12..15, 20..22]);
+/// assert_eq!(a.difference(&b).ranges(), [0..12, 22..25]);
+/// assert_eq!(a.union(&b).ranges(), [0..25]);
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct IntervalSet<T> {
+    ranges: Vec<Range<T>>,
+}
+
+impl<T> Default for IntervalSet<T> {
+    fn default() -> Self {
+        Self { ranges: Vec::new() }
+    }
+}
+
+impl<T: Integer> IntervalSet<T> {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// The ranges in the set, in ascending order
+    pub fn ranges(&self) -> &[Range<T>] {
+        &self.ranges
+    }
+
+    /// The number of integers in the set
+    pub fn len(&self) -> T {
+        self.ranges
+            .iter()
+            .fold(T::ZERO, |total, range| total + (range.end - range.start))
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.ranges.is_empty()
+    }
+
+    /// The smallest integer in the set
+    pub fn min(&self) -> Option<T> {
+        self.ranges.first().map(|range| range.start)
+    }
+
+    /// The largest integer in the set
+    pub fn max(&self) -> Option<T> {
+        self.ranges.last().map(|range| range.end - T::ONE)
+    }
+
+    pub fn contains(&self, value: T) -> bool {
+        // Find the last range starting at or before the value
+        let i = self.ranges.partition_point(|range| range.start <= value);
+        i > 0 && self.ranges[i - 1].end > value
+    }
+
+    /// Add a range of integers to the set
+    pub fn insert(&mut self, range: Range<T>) {
+        self.ranges.push(range);
+        self.normalise();
+    }
+
+    /// Sort the ranges, dropping empty ones and merging any that touch or overlap
+    fn normalise(&mut self) {
+        self.ranges.retain(|range| range.start < range.end);
+        self.ranges.sort_by_key(|range| range.start);
+        let mut merged: Vec<Range<T>> = Vec::with_capacity(self.ranges.len());
+        for range in self.ranges.drain(..) {
+            match merged.last_mut() {
+                Some(last) if range.start <= last.end => last.end = last.end.max(range.end),
+                _ => merged.push(range),
+            }
+        }
+        self.ranges = merged;
+    }
+
+    /// The integers in either set
+    pub fn union(&self, other: &Self) -> Self {
+        self.ranges
+            .iter()
+            .chain(other.ranges.iter())
+            .cloned()
+            .collect()
+    }
+
+    /// The integers in both sets
+    pub fn intersection(&self, other: &Self) -> Self {
+        let (mut i, mut j) = (0, 0);
+        let mut ranges = Vec::new();
+        while let (Some(a), Some(b)) = (self.ranges.get(i), other.ranges.get(j)) {
+            let overlap = a.start.max(b.start)..a.end.min(b.end);
+            if overlap.start < overlap.end {
+                ranges.push(overlap);
+            }
+            // Move past whichever range finishes first
+            if a.end < b.end {
+                i += 1;
+            } else {
+                j += 1;
+            }
+        }
+        Self { ranges }
+    }
+
+    /// The integers in this set but not the other
+    pub fn difference(&self, other: &Self) -> Self {
+        let mut ranges = Vec::new();
+        let mut j = 0;
+        for range in &self.ranges {
+            let mut start = range.start;
+            // Skip ranges that finish before this one starts
+            while other.ranges.get(j).is_some_and(|b| b.end <= start) {
+                j += 1;
+            }
+            let mut k = j;
+            while let Some(b) = other.ranges.get(k).filter(|b| b.start < range.end) {
+                if b.start > start {
+                    ranges.push(start..b.start);
+                }
+                start = start.max(b.end);
+                k += 1;
+            }
+            if start < range.end {
+                ranges.push(start..range.end);
+            }
+        }
+        Self { ranges }
+    }
+}
+
+impl<T: Integer> From<Range<T>> for IntervalSet<T> {
+    fn from(range: Range<T>) -> Self {
+        std::iter::once(range).collect()
+    }
+}
+
+impl<T: Integer> FromIterator<Range<T>> for IntervalSet<T> {
+    fn from_iter<I: IntoIterator<Item = Range<T>>>(iter: I) -> Self {
+        let mut set = Self {
+            ranges: iter.into_iter().collect(),
+        };
+        set.normalise();
+        set
+    }
+}
+
+impl<T: Integer> Extend<Range<T>> for IntervalSet<T> {
+    fn extend<I: IntoIterator<Item = Range<T>>>(&mut self, iter: I) {
+        self.ranges.extend(iter);
+        self.normalise();
+    }
+}
+
+/// A piecewise mapping of integers, where each source range is shifted to start at a
+/// destination value and every other integer maps to itself
+///
+/// If source ranges overlap, the one inserted first is used.
+///
+/// # Examples
+/// ```
+/// use aoc::ranges::RangeMap;
+/// let mut map = RangeMap::new();
+/// map.insert(10..20, 100);
+/// assert_eq!(map.get(15), 105);
+/// assert_eq!(map.get(20), 20);
+///
+/// // Ranges that straddle a boundary are split
+/// assert_eq!(map.map_range(5..12).ranges(), [5..10, 100..102]);
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct RangeMap<T> {
+    entries: Vec<(Range<T>, T)>,
+}
+
+impl<T> Default for RangeMap<T> {
+    fn default() -> Self {
+        Self {
+            entries: Vec::new(),
+        }
+    }
+}
+
+impl<T: Integer> RangeMap<T> {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Map every integer in a source range to the range starting at `destination`
+    pub fn insert(&mut self, source: Range<T>, destination: T) {
+        self.entries.push((source, destination));
+    }
+
+    /// Map a single integer
+    pub fn get(&self, value: T) -> T {
+        self.entries
+            .iter()
+            .find(|(source, _)| source.contains(&value))
+            .map_or(value, |(source, destination)| {
+                *destination + (value - source.start)
+            })
+    }
+
+    /// Map every integer in a range
+    pub fn map_range(&self, range: Range<T>) -> IntervalSet<T> {
+        self.map_set(&IntervalSet::from(range))
+    }
+
+    /// Map every integer in a set
+    pub fn map_set(&self, set: &IntervalSet<T>) -> IntervalSet<T> {
+        let mut unmapped = set.clone();
+        let mut mapped = Vec::new();
+        for (source, destination) in &self.entries {
+            let source = IntervalSet::from(source.clone());
+            let moving = unmapped.intersection(&source);
+            let offset = |value: T| *destination + (value - source.ranges[0].start);
+            mapped.extend(
+                moving
+                    .ranges
+                    .iter()
+                    .map(|range| offset(range.start)..offset(range.end)),
+            );
+            unmapped = unmapped.difference(&source);
+        }
+        mapped.extend(unmapped.ranges);
+        mapped.into_iter().collect()
+    }
+}
+
+impl<T: Integer> FromIterator<(Range<T>, T)> for RangeMap<T> {
+    fn from_iter<I: IntoIterator<Item = (Range<T>, T)>>(iter: I) -> Self {
+        Self {
+            entries: iter.into_iter().collect(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use proptest::prelude::*;
+    use std::collections::BTreeSet;
+
+    fn ranges() -> impl Strategy<Value = Vec<Range<i64>>> {
+        prop::collection::vec((-40..40i64, 0..15i64).prop_map(|(s, l)| s..s + l), 0..5)
+    }
+
+    fn brute(ranges: &[Range<i64>]) -> BTreeSet<i64> {
+        ranges.iter().cloned().flatten().collect()
+    }
+
+    fn members(set: &IntervalSet<i64>) -> BTreeSet<i64> {
+        brute(set.ranges())
+    }
+
+    proptest! {
+        #[test]
+        fn set_operations_match_brute_force(a in ranges(), b in ranges()) {
+            let (set_a, set_b): (IntervalSet<_>, IntervalSet<_>) =
+                (a.iter().cloned().collect(), b.iter().cloned().collect());
+            let (brute_a, brute_b) = (brute(&a), brute(&b));
+
+            prop_assert_eq!(members(&set_a), brute_a.clone());
+            prop_assert_eq!(set_a.len() as usize, brute_a.len());
+            prop_assert!(set_a.ranges().windows(2).all(|w| w[0].end < w[1].start));
+            prop_assert_eq!(members(&set_a.union(&set_b)), &brute_a | &brute_b);
+            prop_assert_eq!(members(&set_a.intersection(&set_b)), &brute_a & &brute_b);
+            prop_assert_eq!(members(&set_a.difference(&set_b)), &brute_a - &brute_b);
+            for x in -45..60 {
+                prop_assert_eq!(set_a.contains(x), brute_a.contains(&x));
+            }
+        }
+
+        #[test]
+        fn range_map_matches_brute_force(
+            entries in prop::collection::vec(((0..50u64, 0..10u64), 0..60u64), 0..4),
+            start in 0..60u64,
+            len in 0..20u64,
+        ) {
+            let map: RangeMap<u64> = entries
+                .iter()
+                .map(|&((s, l), d)| (s..s + l, d))
+                .collect();
+            let expected: BTreeSet<_> = (start..start + len).map(|x| map.get(x)).collect();
+            let mapped = map.map_range(start..start + len);
+            let actual: BTreeSet<_> = mapped.ranges().iter().cloned().flatten().collect();
+            prop_assert_eq!(actual, expected);
+        }
+    }
+
+    #[test]
+    fn extremes() {
+        let set = IntervalSet::from(0..u64::MAX);
+        assert_eq!(set.len(), u64::MAX);
+        assert_eq!(set.max(), Some(u64::MAX - 1));
+        assert!(!set.contains(u64::MAX));
+        assert!(IntervalSet::<i64>::new().min().is_none());
+
+        let map: RangeMap<i64> = [(-10..0, 100)].into_iter().collect();
+        assert_eq!(map.get(-1), 109);
+        assert_eq!(map.map_range(-5..5).ranges(), [0..5, 105..110]);
+    }
+}
@@ -0,0 +1,239 @@
+//! Tiles whose sides connect to their neighbours, like pipes or wires
+//!
+//! # Examples
+//! ```
+//! use aoc::{pos, direction::Dir, grid::{Grid, GridPos}, tiles::Connector};
+//!
+//! let grid: Grid<Connector> = Grid::new(
+//!     ["F-7", "|.|", "L-J"]
+//!         .iter()
+//!         .map(|row| row.chars().map(|c| Connector::try_from(c).unwrap()).collect())
+//!         .collect(),
+//! );
+//! let next: Vec<_> = grid.connected_neighbours(pos!(0, 0)).collect();
+//! assert_eq!(next, vec![(Dir::East, pos!(1, 0)), (Dir::South, pos!(0, 1))]);
+//! ```
+
+use crate::{
+    direction::{Dir, DirSet},
+    grid::{Grid, GridPos},
+};
+
+/// The sides of each connector shape, in the same order as the characters in [`LIGHT`] and
+/// [`HEAVY`]
+const SHAPES: [&[Dir]; 16] = {
+    use Dir::*;
+    [
+        &[],
+        &[North],
+        &[East],
+        &[South],
+        &[West],
+        &[North, South],
+        &[East, West],
+        &[North, East],
+        &[North, West],
+        &[South, West],
+        &[South, East],
+        &[North, East, South],
+        &[North, South, West],
+        &[East, South, West],
+        &[North, East, West],
+        &[North, East, South, West],
+    ]
+};
+
+/// Light box drawing characters for each shape
+const LIGHT: &str = " ╵╶╷╴│─└┘┐┌├┤┬┴┼";
+
+/// Heavy box drawing characters for each shape
+const HEAVY: &str = " ╹╺╻╸┃━┗┛┓┏┣┫┳┻╋";
+
+/// The ASCII characters used for pipes, with the number of the shape they draw
+const ASCII: [(char, usize); 7] = [
+    ('.', 0),
+    ('|', 5),
+    ('-', 6),
+    ('L', 7),
+    ('J', 8),
+    ('7', 9),
+    ('F', 10),
+];
+
+/// A tile that is open on some of its sides
+///
+/// Connectors can be parsed from the ASCII pipes `.|-LJ7F` or from light or heavy box drawing
+/// characters, and are displayed using light box drawing characters.
+///
+/// # Examples
+/// ```
+/// use aoc::{direction::Dir, tiles::Connector};
+/// let bend = Connector::try_from('F').unwrap();
+/// assert!(bend.is_open(Dir::East) && bend.is_open(Dir::South));
+/// assert_eq!(bend.to_string(), "┌");
+/// assert_eq!(Connector::try_from('┌'), Ok(bend));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct Connector(pub DirSet);
+
+impl Connector {
+    /// A tile with no open sides
+    pub const EMPTY: Connector = Connector(DirSet::EMPTY);
+
+    /// Whether the tile is open on a side
+    pub fn is_open(&self, side: Dir) -> bool {
+        self.0.contains(side)
+    }
+
+    /// Whether this tile and the next tile in some direction are both open to each other
+    ///
+    /// # Examples
+    /// ```
+    /// use aoc::{direction::Dir, tiles::Connector};
+    /// let [pipe, bend] = ['-', 'J'].map(|c| Connector::try_from(c).unwrap());
+    /// assert!(pipe.connects(Dir::East, bend));
+    /// assert!(!pipe.connects(Dir::West, bend));
+    /// assert!(!bend.connects(Dir::North, pipe));
+    /// ```
+    pub fn connects(&self, dir: Dir, other: Connector) -> bool {
+        self.is_open(dir) && other.is_open(dir.opposite())
+    }
+
+    /// Work out the shape of an unknown tile, which is open on every side that has a
+    /// neighbouring tile open towards it
+    /// `neighbour` gives the tile in some direction, or None if there isn't one.
+    ///
+    /// # Examples
+    /// ```
+    /// use aoc::{direction::Dir, tiles::Connector};
+    /// let tile = |c| Connector::try_from(c).ok();
+    /// let start = Connector::infer(|dir| match dir {
+    ///     Dir::North => tile('7'),
+    ///     Dir::East => tile('-'),
+    ///     Dir::South => tile('-'),
+    ///     Dir::West => None,
+    /// });
+    /// assert_eq!(start, Connector::try_from('L').unwrap());
+    /// ```
+    pub fn infer<F>(mut neighbour: F) -> Self
+    where
+        F: FnMut(Dir) -> Option<Connector>,
+    {
+        Connector(
+            Dir::ALL
+                .into_iter()
+                .filter(|&dir| neighbour(dir).is_some_and(|other| other.is_open(dir.opposite())))
+                .collect(),
+        )
+    }
+
+    /// The ASCII pipe character for this tile, if it has one
+    pub fn to_ascii(&self) -> Option<char> {
+        ASCII
+            .iter()
+            .find(|&&(_, shape)| *self == Self::shape(shape))
+            .map(|&(c, _)| c)
+    }
+
+    fn shape(index: usize) -> Self {
+        Connector(SHAPES[index].iter().copied().collect())
+    }
+}
+
+impl From<DirSet> for Connector {
+    fn from(open: DirSet) -> Self {
+        Connector(open)
+    }
+}
+
+impl TryFrom<char> for Connector {
+    type Error = String;
+
+    fn try_from(c: char) -> Result<Self, Self::Error> {
+        ASCII
+            .iter()
+            .find(|&&(ascii, _)| ascii == c)
+            .map(|&(_, shape)| shape)
+            .or_else(|| LIGHT.chars().position(|box_char| box_char == c))
+            .or_else(|| HEAVY.chars().position(|box_char| box_char == c))
+            .map(Self::shape)
+            .ok_or_else(|| format!("{c:?} is not a connector tile"))
+    }
+}
+
+impl std::fmt::Display for Connector {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let index = (0..SHAPES.len())
+            .find(|&i| *self == Self::shape(i))
+            .unwrap();
+        write!(f, "{}", LIGHT.chars().nth(index).unwrap())
+    }
+}
+
+impl Grid<Connector> {
+    /// The neighbouring positions that are connected to a tile, along with the direction to
+    /// each of them
+    pub fn connected_neighbours(&self, pos: GridPos) -> impl Iterator<Item = (Dir, GridPos)> + '_ {
+        let tile = self.get(pos).unwrap_or_default();
+        tile.0.iter().filter_map(move |dir| {
+            let next = pos + dir.into();
+            self.get(next)
+                .is_some_and(|other| tile.connects(dir, other))
+                .then_some((dir, next))
+        })
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::pos;
+
+    #[test]
+    fn every_shape_round_trips() {
+        for i in 0..SHAPES.len() {
+            let tile = Connector::shape(i);
+            assert_eq!(
+                Connector::try_from(tile.to_string().chars().next().unwrap()),
+                Ok(tile)
+            );
+            assert_eq!(Connector::try_from(HEAVY.chars().nth(i).unwrap()), Ok(tile));
+            if let Some(c) = tile.to_ascii() {
+                assert_eq!(Connector::try_from(c), Ok(tile));
+            }
+        }
+        assert!(Connector::try_from('S').is_err());
+    }
+
+    #[test]
+    fn follow_loop_with_unknown_start() {
+        // The more complex loop from the 2023 day 10 sample
+        let text = "..F7.\n.FJ|.\nSJ.L7\n|F--J\nLJ...";
+        let start = pos!(0, 2);
+        let mut grid: Grid<Connector> = Grid::new(
+            text.lines()
+                .map(|line| {
+                    line.chars()
+                        .map(|c| Connector::try_from(c).unwrap_or_default())
+                        .collect()
+                })
+                .collect(),
+        );
+        let start_tile = Connector::infer(|dir| grid.get(start + dir.into()));
+        assert_eq!(start_tile.to_ascii(), Some('F'));
+        grid.set(start, start_tile).unwrap();
+
+        // Walk around the loop back to the start
+        let (mut previous, mut pos, mut length) = (start, start, 0);
+        loop {
+            let neighbours: Vec<_> = grid.connected_neighbours(pos).collect();
+            assert_eq!(neighbours.len(), 2);
+            let (_, next) = *neighbours.iter().find(|&&(_, p)| p != previous).unwrap();
+            (previous, pos, length) = (pos, next, length + 1);
+            if pos == start {
+                break;
+            }
+        }
+        assert_eq!(length / 2, 8);
+    }
+}
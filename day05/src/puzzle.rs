@@ -1,3 +1,4 @@
+use aoc::ranges::{IntervalSet, RangeMap};
 use nom::{
     bytes::complete::tag,
     character::complete::{alpha1, newline, u64},
@@ -6,7 +7,6 @@ use nom::{
     sequence::{preceded, separated_pair, terminated, tuple},
     IResult,
 };
-use rayon::prelude::*;
 
 type Category = String;
 
@@ -65,14 +65,12 @@ impl CategoryMap {
         *value
     }
 
-    fn backward(&self, value: &u64) -> u64 {
-        for &(to_start, from_start, len) in &self.map_ranges {
-            if (to_start..to_start + len).contains(value) {
-                let delta = value - to_start;
-                return from_start + delta;
-            }
-        }
-        *value
+    /// The map as shifted ranges, for mapping whole ranges of values at once
+    fn range_map(&self) -> RangeMap<u64> {
+        self.map_ranges
+            .iter()
+            .map(|&(to_start, from_start, len)| (from_start..from_start + len, to_start))
+            .collect()
     }
 }
 
@@ -110,14 +108,12 @@ impl Almanac {
         seed
     }
 
-    /// take a location value and pass it backwards through all maps
-    /// to get a seed
-    fn back_through_all(&self, location: u64) -> u64 {
-        let mut location = location;
-        for category_map in self.maps.iter().rev() {
-            location = category_map.backward(&location);
-        }
-        location
+    /// take a set of seed values and pass them through all maps
+    /// to get the set of locations
+    fn ranges_through_all(&self, seeds: IntervalSet<u64>) -> IntervalSet<u64> {
+        self.maps.iter().fold(seeds, |seeds, category_map| {
+            category_map.range_map().map_set(&seeds)
+        })
     }
 }
 
@@ -142,20 +138,13 @@ pub fn solve_pt1(input: PuzzleInput) -> impl std::fmt::Debug {
 
 /// Solve puzzle part 2
 pub fn solve_pt2(input: PuzzleInput) -> impl std::fmt::Debug {
-    (0..1_000_000_000) // lol
-        .into_par_iter()
-        .find_first(|&location| {
-            // Get possible seed by going backwards
-            let possible_seed = input.back_through_all(location);
-            debug_assert_eq!(input.through_all(possible_seed), location);
-
-            input
-                .initial_seeds
-                .chunks(2)
-                .map(|l| l[0]..l[0] + l[1])
-                .any(|r| r.contains(&possible_seed))
-        })
-        .unwrap()
+    let seeds = input
+        .initial_seeds
+        .chunks(2)
+        .map(|l| l[0]..l[0] + l[1])
+        .collect();
+
+    input.ranges_through_all(seeds).min().unwrap()
 }
 
 #[cfg(test)]
@@ -1,3 +1,6 @@
+use std::cmp::Ordering;
+
+use aoc::math::quadratic_integer_range;
 use nom::{
     bytes::complete::tag,
     character::complete::{newline, space1, u64},
@@ -18,15 +21,16 @@ pub struct Race {
 }
 
 impl Race {
-    /// Button holding durations that will beat the record
-    fn winning_button_press_durations(&self) -> Vec<u64> {
-        // dist(bt, rt) = (rt - bt) * bt
-        (0..=self.time)
-            .filter(|button_duration| {
-                let dist = (self.time - button_duration) * button_duration;
-                dist > self.record_distance
-            })
-            .collect()
+    /// Number of button holding durations that will beat the record
+    fn winning_button_press_count(&self) -> usize {
+        // dist(bt, rt) = (rt - bt) * bt, so we need -bt² + rt * bt - record > 0
+        let durations = quadratic_integer_range(
+            -1,
+            self.time as i128,
+            -(self.record_distance as i128),
+            Ordering::Greater,
+        );
+        (durations.end() - durations.start() + 1).max(0) as usize
     }
 }
 
@@ -88,14 +92,14 @@ pub fn solve_pt1(input: PuzzleInput) -> impl std::fmt::Debug {
     input
         .0
         .iter()
-        .map(|race| race.winning_button_press_durations().len())
+        .map(|race| race.winning_button_press_count())
         .product::<usize>()
 }
 
 /// Solve puzzle part 2
 pub fn solve_pt2(input: PuzzleInput) -> impl std::fmt::Debug {
     let race = input.reinterpret_as_kerning();
-    race.winning_button_press_durations().len()
+    race.winning_button_press_count()
 }
 
 #[cfg(test)]
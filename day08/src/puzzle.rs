@@ -1,5 +1,6 @@
 use std::collections::HashMap;
 
+use aoc::math::lcm;
 use nom::{
     branch::alt,
     bytes::complete::tag,
@@ -122,20 +123,6 @@ pub fn solve_pt2(input: PuzzleInput) -> impl std::fmt::Debug {
     input.ghost_path_length()
 }
 
-/// Calculate greatest common divisor
-fn gcd(a: usize, b: usize) -> usize {
-    if a > 0 {
-        gcd(b % a, a)
-    } else {
-        b
-    }
-}
-
-/// Calculate least common multiple
-fn lcm(a: usize, b: usize) -> usize {
-    (a * b) / gcd(a, b)
-}
-
 #[cfg(test)]
 mod test {
     use super::*;
@@ -1,4 +1,4 @@
-use itertools::Itertools;
+use aoc::math::poly::Polynomial;
 use nom::{
     bytes::complete::tag,
     character::complete::{i32, i64},
@@ -18,69 +18,19 @@ impl History {
         map(separated_list1(tag(" "), i64), History)(input)
     }
 
-    // Find sequences down to 0s
-    fn fill(&self) -> Vec<Vec<i64>> {
-        let mut seqs = vec![self.0.clone()];
-        let mut curr_seq = self.0.clone();
-        loop {
-            let diffs: Vec<i64> = curr_seq
-                .clone()
-                .into_iter()
-                .map_windows(|[a, b]| b - a)
-                .collect();
-
-            if diffs.iter().all(|&s| s == 0) {
-                break;
-            } else {
-                seqs.push(diffs.clone());
-                curr_seq = diffs;
-            }
-        }
-
-        seqs
+    /// Fit the lowest degree polynomial through the history
+    fn polynomial(&self) -> Polynomial {
+        Polynomial::fit(self.0.iter().copied()).unwrap()
     }
 
     /// Extrapolate to get next value in the sequence
     fn extrapolate(&self) -> i64 {
-        let mut seqs = self.fill();
-
-        // Go back up adding values
-        for i in (0..seqs.len()).rev() {
-            // Get last value from previous row if applicable
-            let prev = if let Some(prev_seq) = seqs.get(i + 1) {
-                *prev_seq.last().unwrap()
-            } else {
-                0
-            };
-
-            // Add previous value to value at end of current sequence
-            let curr_seq = &mut seqs[i];
-            let last = curr_seq.last().unwrap();
-            curr_seq.push(last + prev);
-        }
-
-        *seqs[0].last().unwrap()
+        self.polynomial().at(self.0.len() as i128).unwrap() as i64
     }
 
+    /// Extrapolate to get the value before the start of the sequence
     fn extrapolate_backwards(&self) -> i64 {
-        let mut seqs = self.fill();
-
-        // Go back up subtracting values
-        for i in (0..seqs.len()).rev() {
-            // Get first value from previous row if applicable
-            let prev = if let Some(prev_seq) = seqs.get(i + 1) {
-                *prev_seq.first().unwrap()
-            } else {
-                0
-            };
-
-            // Add previous value to value at end of current sequence
-            let curr_seq = &mut seqs[i];
-            let first = curr_seq.first().unwrap();
-            curr_seq.insert(0, first - prev);
-        }
-
-        *seqs[0].first().unwrap()
+        self.polynomial().at(-1).unwrap() as i64
     }
 }
 
@@ -1,27 +1,14 @@
-use std::collections::HashSet;
-
 use aoc::{
-    direction::{Dir, OrthDir},
+    direction::{Dir, DirSet, Mirror, OrthDir, Pose},
     grid::{Grid, GridPos},
     pos,
 };
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
-pub enum MirrorDir {
-    /// `/`
-    /// rotates horizontal light to its left
-    Left,
-
-    /// `\`
-    /// rotates horizontal light to its right
-    Right,
-}
-
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum Cell {
     Empty,
     Splitter(OrthDir),
-    Mirror(MirrorDir),
+    Mirror(Mirror),
 }
 
 impl Cell {
@@ -30,8 +17,7 @@ impl Cell {
             '.' => Cell::Empty,
             '-' => Cell::Splitter(OrthDir::Horizontal),
             '|' => Cell::Splitter(OrthDir::Vertical),
-            '/' => Cell::Mirror(MirrorDir::Left),
-            '\\' => Cell::Mirror(MirrorDir::Right),
+            '/' | '\\' => Cell::Mirror(Mirror::try_from(c).unwrap()),
             _ => panic!(),
         }
     }
@@ -40,7 +26,7 @@ impl Cell {
 #[derive(Debug, Clone)]
 pub struct Floor {
     layout: Grid<Cell>,
-    dir_history: Grid<HashSet<Dir>>,
+    dir_history: Grid<DirSet>,
 }
 
 impl Floor {
@@ -57,19 +43,18 @@ impl Floor {
         }
     }
 
-    fn trace_beam(&mut self, from: GridPos, direction: Dir) {
+    fn trace_beam(&mut self, mut beam: Pose) {
         // Find next non empty point
-        let mut pos = from;
-        while self.layout.get(pos) == Some(Cell::Empty) {
-            if !self.dir_history.get_mut(pos).unwrap().insert(direction) {
+        while self.layout.get(beam.pos) == Some(Cell::Empty) {
+            if !self.dir_history.get_mut(beam.pos).unwrap().insert(beam.dir) {
                 return;
             }
 
-            pos += direction.into();
+            beam = beam.step();
         }
 
         // Where did we end up?
-        let cell = match self.layout.get(pos) {
+        let cell = match self.layout.get(beam.pos) {
             // Did we go outside the grid?
             None => {
                 return;
@@ -81,31 +66,19 @@ impl Floor {
 
         // Record this pos+dir
         // If seen before, exit early
-        if !self.dir_history.get_mut(pos).unwrap().insert(direction) {
+        if !self.dir_history.get_mut(beam.pos).unwrap().insert(beam.dir) {
             return;
         }
 
         // Did we go outside the grid? if so terminate
         match cell {
-            // Splitter that splits
-            Cell::Splitter(orth_dir) if orth_dir != direction.orthogonal() => {
-                self.trace_beam(pos + direction.turn_left().into(), direction.turn_left());
-                self.trace_beam(pos + direction.turn_right().into(), direction.turn_right())
+            Cell::Splitter(orth_dir) => {
+                for new_dir in orth_dir.split(beam.dir) {
+                    self.trace_beam(beam.face(new_dir).step())
+                }
             }
 
-            // Splitter that doesn't split
-            Cell::Splitter(_) => self.trace_beam(pos + direction.into(), direction),
-
-            Cell::Mirror(mirror_dir) => {
-                let new_dir = match (mirror_dir, direction.orthogonal()) {
-                    (MirrorDir::Left, OrthDir::Horizontal) => direction.turn_left(),
-                    (MirrorDir::Left, OrthDir::Vertical) => direction.turn_right(),
-                    (MirrorDir::Right, OrthDir::Horizontal) => direction.turn_right(),
-                    (MirrorDir::Right, OrthDir::Vertical) => direction.turn_left(),
-                };
-
-                self.trace_beam(pos + new_dir.into(), new_dir)
-            }
+            Cell::Mirror(mirror) => self.trace_beam(beam.face(beam.dir.reflect(mirror)).step()),
 
             Cell::Empty => unreachable!(),
         }
@@ -128,24 +101,19 @@ pub fn parse_input(input_text: &str) -> PuzzleInput {
 
 /// Solve puzzle part 1
 pub fn solve_pt1(mut input: PuzzleInput) -> impl std::fmt::Debug {
-    input.trace_beam(pos!(0, 0), Dir::East);
+    input.trace_beam(Pose::new(pos!(0, 0), Dir::East));
     input.energy_level()
 }
 
 /// Solve puzzle part 2
 pub fn solve_pt2(input: PuzzleInput) -> usize {
-    let (width, height) = (input.layout.width, input.layout.height);
-    let left = (0..height).map(|y| (pos!(0, y), Dir::East));
-    let right = (0..height).map(|y| (pos!(width - 1, y), Dir::West));
-    let top = (0..width).map(|x| (pos!(x, 0), Dir::South));
-    let bottom = (0..width).map(|x| (pos!(x, height - 1), Dir::North));
-
-    left.chain(right)
-        .chain(top)
-        .chain(bottom)
-        .map(|(from, direction)| {
+    input
+        .layout
+        .bounds()
+        .edges()
+        .map(|(side, from)| {
             let mut input = input.clone();
-            input.trace_beam(from, direction);
+            input.trace_beam(Pose::new(from, side.opposite()));
             input.energy_level()
         })
         .max()
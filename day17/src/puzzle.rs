@@ -5,7 +5,7 @@ use std::{
 };
 
 use aoc::{
-    direction::Dir,
+    direction::{Dir, Turn},
     grid::{Grid, GridPos},
     pos,
 };
@@ -139,16 +139,14 @@ impl<'a> PathSearch<'a> {
             // Expand
             state
                 .pos
-                .neighbours()
-                .filter(|pos| pos.in_grid(&search.read().unwrap().city.0))
-                .filter_map(|pos| {
-                    let dir = Dir::try_from(pos - state.pos).unwrap();
-
+                .neighbours_with_dir()
+                .filter(|(_, pos)| pos.in_grid(&search.read().unwrap().city.0))
+                .filter_map(|(dir, pos)| {
                     // If same, keep the dirs
                     let previous_dir = state.previous_same_dirs.last();
 
                     // Can't go backwards
-                    if previous_dir == Some(&dir.opposite()) {
+                    if previous_dir.is_some_and(|previous| previous.turn_to(dir) == Turn::Back) {
                         return None;
                     }
 